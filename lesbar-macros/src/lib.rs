@@ -1,27 +1,300 @@
 #![no_std]
 
+extern crate alloc;
 extern crate proc_macro;
 
-use lesbar_text::StrExt as _;
+use alloc::format;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use lesbar_text::diagnosis::Diagnosis;
+use lesbar_text::policy::{LegibilityPolicy, Lenient, NonBlank, SingleLine, Standard, Strict};
 use mitsein::str1::Str1;
 use proc_macro::TokenStream;
-use syn::LitStr;
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
+use syn::{GenericArgument, LitStr, Path, PathArguments, Token, Type};
+
+// Legibility policies that can be evaluated by procedural macros. Procedural macros cannot execute
+// code from the crates that invoke them, so only the policies provided by `lesbar-text` are
+// supported.
+#[derive(Clone, Copy, Default)]
+enum BasePolicy {
+    #[default]
+    Standard,
+    Strict,
+    Lenient,
+}
+
+impl BasePolicy {
+    fn diagnose<W>(&self, text: &str) -> Option<Diagnosis>
+    where
+        W: Wrap,
+    {
+        match self {
+            BasePolicy::Standard => W::diagnose::<Standard>(text),
+            BasePolicy::Strict => W::diagnose::<Strict>(text),
+            BasePolicy::Lenient => W::diagnose::<Lenient>(text),
+        }
+    }
+}
+
+impl ToTokens for BasePolicy {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(match self {
+            BasePolicy::Standard => quote::quote! { ::lesbar::policy::Standard },
+            BasePolicy::Strict => quote::quote! { ::lesbar::policy::Strict },
+            BasePolicy::Lenient => quote::quote! { ::lesbar::policy::Lenient },
+        })
+    }
+}
+
+// Policy combinators that wrap another policy, such as `NonBlank<P>`. Implementations wrap the
+// policy `P` and then defer to the next (outer) combinator `W`.
+trait Wrap {
+    fn diagnose<P>(text: &str) -> Option<Diagnosis>
+    where
+        P: LegibilityPolicy;
+}
+
+enum Unwrapped {}
+
+impl Wrap for Unwrapped {
+    fn diagnose<P>(text: &str) -> Option<Diagnosis>
+    where
+        P: LegibilityPolicy,
+    {
+        P::diagnose(text)
+    }
+}
+
+struct WrapNonBlank<W>(PhantomData<fn() -> W>);
+
+impl<W> Wrap for WrapNonBlank<W>
+where
+    W: Wrap,
+{
+    fn diagnose<P>(text: &str) -> Option<Diagnosis>
+    where
+        P: LegibilityPolicy,
+    {
+        W::diagnose::<NonBlank<P>>(text)
+    }
+}
+
+struct WrapSingleLine<W>(PhantomData<fn() -> W>);
+
+impl<W> Wrap for WrapSingleLine<W>
+where
+    W: Wrap,
+{
+    fn diagnose<P>(text: &str) -> Option<Diagnosis>
+    where
+        P: LegibilityPolicy,
+    {
+        W::diagnose::<SingleLine<P>>(text)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Combinator {
+    NonBlank,
+    SingleLine,
+}
+
+impl Combinator {
+    const ALL: [Combinator; 2] = [Combinator::NonBlank, Combinator::SingleLine];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Combinator::NonBlank => "NonBlank",
+            Combinator::SingleLine => "SingleLine",
+        }
+    }
+}
+
+impl ToTokens for Combinator {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(match self {
+            Combinator::NonBlank => quote::quote! { ::lesbar::policy::NonBlank },
+            Combinator::SingleLine => quote::quote! { ::lesbar::policy::SingleLine },
+        })
+    }
+}
+
+// A base policy wrapped by any number of distinct combinators. Combinators are ordered from
+// outermost to innermost.
+#[derive(Clone, Default)]
+struct Policy {
+    combinators: Vec<Combinator>,
+    base: BasePolicy,
+}
+
+impl Policy {
+    // The path of the module that provides legibility policies.
+    const MODULE: [&'static str; 2] = ["lesbar", "policy"];
+
+    // Determines whether or not the given path refers to an item in the `policy` module. Paths may
+    // be qualified by any suffix of the module path, such as `policy::Strict`, or fully qualified,
+    // such as `::lesbar::policy::Strict`. Other paths (such as `my::Strict`) are not supported,
+    // because they may refer to any type.
+    fn is_module_path(path: &Path) -> bool {
+        let n = path.segments.len().saturating_sub(1);
+        n <= Policy::MODULE.len()
+            && (path.leading_colon.is_none() || n == Policy::MODULE.len())
+            && path
+                .segments
+                .iter()
+                .take(n)
+                .zip(&Policy::MODULE[(Policy::MODULE.len() - n)..])
+                .all(|(segment, name)| segment.ident == name && segment.arguments.is_none())
+    }
+
+    fn wrap(mut self, combinator: Combinator) -> Option<Self> {
+        (!self.combinators.contains(&combinator)).then(|| {
+            self.combinators.insert(0, combinator);
+            self
+        })
+    }
+
+    fn diagnose(&self, text: &str) -> Option<Diagnosis> {
+        use Combinator::{NonBlank, SingleLine};
+
+        match self.combinators.as_slice() {
+            [] => self.base.diagnose::<Unwrapped>(text),
+            [NonBlank] => self.base.diagnose::<WrapNonBlank<Unwrapped>>(text),
+            [SingleLine] => self.base.diagnose::<WrapSingleLine<Unwrapped>>(text),
+            [NonBlank, SingleLine] => self
+                .base
+                .diagnose::<WrapSingleLine<WrapNonBlank<Unwrapped>>>(text),
+            [SingleLine, NonBlank] => self
+                .base
+                .diagnose::<WrapNonBlank<WrapSingleLine<Unwrapped>>>(text),
+            _ => unreachable!("combinators are not distinct"),
+        }
+    }
+}
+
+impl Parse for Policy {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path: Path = input.parse()?;
+        let unsupported = || syn::Error::new_spanned(&path, "unsupported legibility policy");
+        if !Policy::is_module_path(&path) {
+            return Err(unsupported());
+        }
+        let segment = path.segments.last().ok_or_else(unsupported)?;
+        if let Some(combinator) = Combinator::ALL
+            .into_iter()
+            .find(|combinator| segment.ident == combinator.name())
+        {
+            let policy = match &segment.arguments {
+                PathArguments::None => Policy::default(),
+                PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+                    Some(GenericArgument::Type(Type::Path(inner))) if arguments.args.len() == 1 => {
+                        syn::parse2::<Policy>(inner.to_token_stream())?
+                    },
+                    _ => return Err(unsupported()),
+                },
+                _ => return Err(unsupported()),
+            };
+            policy.wrap(combinator).ok_or_else(unsupported)
+        }
+        else {
+            segment
+                .arguments
+                .is_none()
+                .then(|| {
+                    [
+                        ("Standard", BasePolicy::Standard),
+                        ("Strict", BasePolicy::Strict),
+                        ("Lenient", BasePolicy::Lenient),
+                    ]
+                    .into_iter()
+                    .find_map(|(name, base)| (segment.ident == name).then_some(base))
+                })
+                .flatten()
+                .map(|base| Policy {
+                    combinators: Vec::new(),
+                    base,
+                })
+                .ok_or_else(unsupported)
+        }
+    }
+}
+
+impl ToTokens for Policy {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let policy = self.combinators.iter().rev().fold(
+            self.base.to_token_stream(),
+            |policy, combinator| quote::quote! { #combinator<#policy> },
+        );
+        tokens.extend(policy)
+    }
+}
+
+struct TextInput {
+    policy: Policy,
+    literal: LitStr,
+}
+
+impl Parse for TextInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            Ok(TextInput {
+                policy: Policy::default(),
+                literal: input.parse()?,
+            })
+        }
+        else {
+            let policy = input.parse()?;
+            input.parse::<Token![,]>()?;
+            Ok(TextInput {
+                policy,
+                literal: input.parse()?,
+            })
+        }
+    }
+}
 
 #[proc_macro]
 pub fn text(input: TokenStream) -> TokenStream {
-    let literal = syn::parse_macro_input!(input as LitStr);
-    if literal.value().has_legible_text() {
+    let TextInput { policy, literal } = syn::parse_macro_input!(input as TextInput);
+    legible(policy, literal)
+}
+
+#[proc_macro]
+pub fn visible(input: TokenStream) -> TokenStream {
+    wrapped(input, Combinator::NonBlank)
+}
+
+#[proc_macro]
+pub fn line(input: TokenStream) -> TokenStream {
+    wrapped(input, Combinator::SingleLine)
+}
+
+fn wrapped(input: TokenStream, combinator: Combinator) -> TokenStream {
+    let TextInput { policy, literal } = syn::parse_macro_input!(input as TextInput);
+    match policy.wrap(combinator) {
+        Some(policy) => legible(policy, literal),
+        _ => syn::Error::new_spanned(literal, format!("redundant `{}` policy", combinator.name()))
+            .to_compile_error()
+            .into(),
+    }
+}
+
+fn legible(policy: Policy, literal: LitStr) -> TokenStream {
+    if let Some(diagnosis) = policy.diagnose(&literal.value()) {
+        let message = format!("string literal has no legible text: {diagnosis}");
         quote::quote! {
-            // SAFETY: The procedural macro that generated this code has established that the
-            //         string literal is non-empty. See `lesbar_text::StrExt::has_text`.
-            ::lesbar::text::Text::from_str1_unchecked(unsafe {
-                ::mitsein::str1::Str1::from_str_unchecked(#literal)
-            })
+            ::core::compile_error!(#message)
         }
     }
     else {
         quote::quote! {
-            ::core::compile_error!("string literal has no legible text")
+            // SAFETY: The procedural macro that generated this code has established that the
+            //         string literal is non-empty. See `lesbar_text::StrExt::has_text`.
+            ::lesbar::Legible::<str, #policy>::from_str1_unchecked(unsafe {
+                ::mitsein::str1::Str1::from_str_unchecked(#literal)
+            })
         }
     }
     .into()
@@ -0,0 +1,290 @@
+//! Diagnostics that describe why text is illegible.
+
+use core::fmt::{self, Display, Formatter};
+use core::ops::Range;
+use unicode_properties::UnicodeGeneralCategory;
+
+use crate::grapheme::Grapheme;
+use crate::policy::LegibilityPolicy;
+use crate::{property, StrExt as _};
+
+pub use unicode_properties::GeneralCategory;
+
+/// The reason that text is illegible.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Illegibility {
+    /// The text is empty.
+    Empty,
+    /// The text has only default-ignorable code points, such as zero-width spaces and joiners.
+    DefaultIgnorable,
+    /// The text has only whitespace.
+    Whitespace,
+    /// The text has only zero-width formatting characters.
+    Format,
+    /// The text has only combining marks and zero-width formatting characters.
+    CombiningMark,
+    /// The text has only private-use characters.
+    PrivateUse,
+    /// The text has only unassigned code points.
+    Unassigned,
+    /// The text has a line break, such as a line or paragraph separator.
+    ///
+    /// This is reported by policies that forbid line breaks, such as [`SingleLine`].
+    ///
+    /// [`SingleLine`]: crate::policy::SingleLine
+    LineBreak,
+    /// The text has a grapheme cluster with too many combining marks, such as "Zalgo" text.
+    ///
+    /// This is reported by policies that limit combining marks, such as [`MaxCombiningMarks`].
+    ///
+    /// [`MaxCombiningMarks`]: crate::policy::MaxCombiningMarks
+    CombiningMarkLimit,
+    /// The text has no legible grapheme clusters for some other reason.
+    Other,
+}
+
+impl Illegibility {
+    /// Classifies the code points of illegible text.
+    ///
+    /// The classification is based only on the code points of `text` and does not consider any
+    /// legibility policy, so `text` is assumed to be illegible.
+    pub fn classify(text: &str) -> Self {
+        let all = |f: fn(char) -> bool| text.chars().all(f);
+        if text.is_empty() {
+            Illegibility::Empty
+        }
+        else if all(property::is_default_ignorable) {
+            Illegibility::DefaultIgnorable
+        }
+        else if all(char::is_whitespace) {
+            Illegibility::Whitespace
+        }
+        else if all(is_format) {
+            Illegibility::Format
+        }
+        else if all(|point| is_format(point) || property::is_combining_mark(point)) {
+            Illegibility::CombiningMark
+        }
+        else if all(|point| matches!(point.general_category(), GeneralCategory::PrivateUse)) {
+            Illegibility::PrivateUse
+        }
+        else if all(|point| matches!(point.general_category(), GeneralCategory::Unassigned)) {
+            Illegibility::Unassigned
+        }
+        else {
+            Illegibility::Other
+        }
+    }
+}
+
+impl Display for Illegibility {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Illegibility::Empty => "empty",
+            Illegibility::DefaultIgnorable => "only default-ignorable code points",
+            Illegibility::Whitespace => "only whitespace",
+            Illegibility::Format => "only zero-width formatting characters",
+            Illegibility::CombiningMark => "only combining marks and formatting characters",
+            Illegibility::PrivateUse => "only private-use characters",
+            Illegibility::Unassigned => "only unassigned code points",
+            Illegibility::LineBreak => "line or paragraph separator",
+            Illegibility::CombiningMarkLimit => "too many combining marks",
+            Illegibility::Other => "no legible grapheme clusters",
+        })
+    }
+}
+
+/// Describes why text is illegible and where.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Diagnosis {
+    illegibility: Illegibility,
+    span: Option<(usize, usize)>,
+}
+
+impl Diagnosis {
+    pub fn new(illegibility: Illegibility, span: Option<Range<usize>>) -> Self {
+        Diagnosis {
+            illegibility,
+            span: span.map(|span| (span.start, span.end)),
+        }
+    }
+
+    /// Diagnoses text that has no legible grapheme clusters.
+    ///
+    /// The span of the diagnosis is that of the first grapheme cluster in `text`, if any.
+    pub fn from_illegible_str(text: &str) -> Self {
+        Diagnosis::new(
+            Illegibility::classify(text),
+            text.grapheme_indices()
+                .next()
+                .map(|(start, grapheme)| start..(start + grapheme.as_str().len())),
+        )
+    }
+
+    pub const fn empty() -> Self {
+        Diagnosis {
+            illegibility: Illegibility::Empty,
+            span: None,
+        }
+    }
+
+    pub const fn illegibility(&self) -> Illegibility {
+        self.illegibility
+    }
+
+    /// Gets the byte span of the first offending grapheme cluster, if any.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.map(|(start, end)| start..end)
+    }
+}
+
+impl Display for Diagnosis {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self.span {
+            Some((start, end)) => {
+                write!(formatter, "{} at bytes {start}..{end}", self.illegibility)
+            },
+            _ => write!(formatter, "{}", self.illegibility),
+        }
+    }
+}
+
+/// Describes the legibility of a grapheme cluster in a string slice.
+///
+/// See [`StrExt::explain_legibility`].
+///
+/// [`StrExt::explain_legibility`]: crate::StrExt::explain_legibility
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Explanation<'t> {
+    index: usize,
+    grapheme: &'t Grapheme,
+    is_legible: bool,
+}
+
+impl<'t> Explanation<'t> {
+    pub(crate) fn explain<P>(index: usize, grapheme: &'t Grapheme) -> Self
+    where
+        P: LegibilityPolicy,
+    {
+        Explanation {
+            index,
+            grapheme,
+            is_legible: P::is_legible_grapheme(grapheme),
+        }
+    }
+
+    /// Gets the byte offset of the grapheme cluster.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the byte span of the grapheme cluster.
+    pub fn span(&self) -> Range<usize> {
+        self.index..(self.index + self.grapheme.as_str().len())
+    }
+
+    pub const fn grapheme(&self) -> &'t Grapheme {
+        self.grapheme
+    }
+
+    /// Gets the display width of the grapheme cluster per UCS and UAX11.
+    pub fn width(&self) -> usize {
+        self.grapheme.width()
+    }
+
+    /// Gets the general category of the first code point in the grapheme cluster.
+    ///
+    /// The first code point is the base of the grapheme cluster with the exception of prepended
+    /// code points, which are rare.
+    pub fn category(&self) -> GeneralCategory {
+        self.grapheme.chars1().first().general_category()
+    }
+
+    pub fn is_private_use_character(&self) -> bool {
+        self.grapheme.is_private_use_character()
+    }
+
+    /// Returns `true` if the grapheme cluster is legible per the policy used to explain it.
+    pub const fn is_legible_text(&self) -> bool {
+        self.is_legible
+    }
+}
+
+fn is_format(point: char) -> bool {
+    property::is_default_ignorable(point)
+        || matches!(point.general_category(), GeneralCategory::Format)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+
+    use crate::diagnosis::{Diagnosis, GeneralCategory, Illegibility};
+    use crate::policy::Lenient;
+    use crate::StrExt as _;
+
+    #[rstest]
+    #[case::empty("", Illegibility::Empty)]
+    #[case::zwsp("\u{200B}", Illegibility::DefaultIgnorable)]
+    #[case::bidi("\u{200E}\u{202A}", Illegibility::DefaultIgnorable)]
+    #[case::spaces("  ", Illegibility::Whitespace)]
+    #[case::kaithi_number_sign_and_zwsp("\u{200B}\u{110BD}", Illegibility::Format)]
+    #[case::acute("\u{301}", Illegibility::CombiningMark)]
+    #[case::acute_and_zwj("\u{301}\u{200D}", Illegibility::CombiningMark)]
+    #[case::private_use("\u{E000}", Illegibility::PrivateUse)]
+    #[case::unassigned("\u{0378}", Illegibility::Unassigned)]
+    #[case::mixed("\u{0}\u{301}", Illegibility::Other)]
+    fn classify_illegible_str_then_illegibility_eq(
+        #[case] text: &str,
+        #[case] expected: Illegibility,
+    ) {
+        assert_eq!(Illegibility::classify(text), expected);
+    }
+
+    #[rstest]
+    #[case::empty("", None)]
+    #[case::one("\u{200B}", Some(0..3))]
+    #[case::many("\u{200B}\u{200E}", Some(0..3))]
+    fn diagnose_illegible_str_then_span_eq(
+        #[case] text: &str,
+        #[case] expected: Option<core::ops::Range<usize>>,
+    ) {
+        assert_eq!(Diagnosis::from_illegible_str(text).span(), expected);
+    }
+
+    #[rstest]
+    fn explain_legibility_of_str_then_explanations_eq() {
+        let explanations: std::vec::Vec<_> = "\u{200B}a\u{E000}".explain_legibility().collect();
+        assert_eq!(explanations.len(), 3);
+
+        let zwsp = &explanations[0];
+        assert_eq!((zwsp.index(), zwsp.width()), (0, 0));
+        assert_eq!(zwsp.category(), GeneralCategory::Format);
+        assert!(!zwsp.is_legible_text());
+
+        let a = &explanations[1];
+        assert_eq!((a.index(), a.width()), (3, 1));
+        assert_eq!(a.category(), GeneralCategory::LowercaseLetter);
+        assert!(a.is_legible_text());
+
+        let private_use = &explanations[2];
+        assert_eq!(private_use.span(), 4..7);
+        assert!(private_use.is_private_use_character());
+        assert!(private_use.is_legible_text());
+    }
+
+    #[rstest]
+    fn explain_legibility_of_combining_str_with_lenient_policy_then_legible() {
+        let mut explanations = "\u{301}".explain_legibility_with::<Lenient>();
+        assert!(explanations.next().unwrap().is_legible_text());
+        assert!(explanations.next().is_none());
+        assert!(!"\u{301}"
+            .explain_legibility()
+            .next()
+            .unwrap()
+            .is_legible_text());
+    }
+}
@@ -8,7 +8,9 @@ use mitsein::str1::Str1;
 use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
 use unicode_width::UnicodeWidthStr;
 
-use crate::RuneError;
+use crate::policy::LegibilityPolicy;
+use crate::width::WidthContext;
+use crate::{property, RuneError};
 
 #[cfg(feature = "alloc")]
 pub use crate::grapheme::buf::*;
@@ -75,11 +77,117 @@ impl Grapheme {
         self.is_private_use_character() || self.width() != 0
     }
 
+    /// Gets the display width of the grapheme cluster in columns per UCS and UAX11.
+    ///
+    /// The display width of a grapheme cluster may be zero, such as for a zero-width space.
+    pub fn width(&self) -> usize {
+        UnicodeWidthStr::width(self.as_str())
+    }
+
+    /// Gets the display width of the grapheme cluster in columns per UCS and UAX11 in a CJK
+    /// context.
+    ///
+    /// See [`WidthContext::Cjk`].
+    pub fn width_cjk(&self) -> usize {
+        UnicodeWidthStr::width_cjk(self.as_str())
+    }
+
+    /// Gets the display width of the grapheme cluster in columns per UCS and UAX11 in the given
+    /// context.
+    pub fn width_in(&self, context: WidthContext) -> usize {
+        context.width(self.as_str())
+    }
+
+    pub fn is_legible_text_with<P>(&self) -> bool
+    where
+        P: LegibilityPolicy,
+    {
+        P::is_legible_grapheme(self)
+    }
+
+    /// Returns `true` if all code points in the grapheme cluster are whitespace.
+    ///
+    /// Whitespace is determined by the `White_Space` property. See [`char::is_whitespace`].
+    pub fn is_whitespace(&self) -> bool {
+        self.as_str().chars().all(char::is_whitespace)
+    }
+
+    /// Returns `true` if the grapheme cluster is a line break, such as `\n`, `\r\n`, or a line or
+    /// paragraph separator.
+    ///
+    /// Line breaks are the mandatory breaks of UAX14.
+    pub fn is_line_break(&self) -> bool {
+        self.as_str().chars().any(property::is_line_break)
+    }
+
+    /// Gets an iterator over the default-ignorable code points in the grapheme cluster and their
+    /// byte indices.
+    ///
+    /// Default-ignorable code points, such as zero-width joiners, variation selectors, and tag
+    /// characters, have no visible presentation of their own, even in legible grapheme clusters.
+    pub fn default_ignorable_indices(&self) -> impl '_ + Iterator<Item = (usize, char)> {
+        self.as_str()
+            .char_indices()
+            .filter(|&(_, point)| property::is_default_ignorable(point))
+    }
+
+    /// Gets the number of combining marks in the grapheme cluster.
+    ///
+    /// Combining marks are code points in the general categories `Mn`, `Mc`, and `Me` except for
+    /// variation selectors, which select a glyph rather than stack upon it. Grapheme clusters may
+    /// have any number of combining marks, such as a base letter with many stacked diacritics
+    /// (sometimes called "Zalgo" text). See [`MaxCombiningMarks`].
+    ///
+    /// [`MaxCombiningMarks`]: crate::policy::MaxCombiningMarks
+    pub fn combining_mark_count(&self) -> usize {
+        self.as_str()
+            .chars()
+            .filter(|&point| property::is_stacking_mark(point))
+            .count()
+    }
+
     pub fn is_private_use_character(&self) -> bool {
         self.to_char()
             .map(UnicodeGeneralCategory::general_category)
             .is_some_and(|category| matches!(category, GeneralCategory::PrivateUse))
     }
+
+    // Gets the code points of the grapheme cluster that are not invisible. See
+    // `StrExt::strip_invisible`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn visible_points(&self) -> impl '_ + Iterator<Item = char> {
+        let base = self
+            .as_str()
+            .chars()
+            .find(|&point| !property::is_default_ignorable(point));
+        let is_emoji = base.is_some_and(property::is_emoji);
+        self.as_str()
+            .char_indices()
+            .filter(move |&(index, point)| {
+                base.is_some()
+                    && (!property::is_default_ignorable(point)
+                        || (index > 0 && property::is_variation_selector(point))
+                        || property::is_conjoining_hangul_filler(point)
+                        || (is_emoji && property::is_emoji_joiner(point)))
+            })
+            .map(|(_, point)| point)
+    }
+
+    // Gets the code points of the grapheme cluster with all but the first `n` combining marks
+    // removed. See `StrExt::limit_combining_marks`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn limited_points(&self, n: usize) -> impl '_ + Iterator<Item = char> {
+        let mut count = 0usize;
+        self.as_str().chars().filter(move |&point| {
+            if property::is_stacking_mark(point) {
+                count += 1;
+                count <= n
+            }
+            else {
+                true
+            }
+        })
+    }
 }
 
 impl AsRef<Grapheme> for Grapheme {
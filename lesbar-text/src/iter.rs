@@ -1,6 +1,15 @@
+use core::fmt::{self, Debug, Formatter};
+use core::iter::Peekable;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::Range;
+use core::str::CharIndices;
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::diagnosis::Explanation;
 use crate::grapheme::Grapheme;
+use crate::policy::{LegibilityPolicy, Standard};
+use crate::property;
 
 #[derive(Clone, Debug)]
 pub struct Graphemes<'t> {
@@ -75,3 +84,241 @@ impl<'t> Iterator for GraphemeIndices<'t> {
             .map(|(index, grapheme)| unsafe { (index, Grapheme::from_str_unchecked(grapheme)) })
     }
 }
+
+pub struct Explanations<'t, P = Standard> {
+    input: GraphemeIndices<'t>,
+    policy: PhantomData<fn() -> P>,
+}
+
+impl<'t, P> Explanations<'t, P> {
+    pub(crate) fn from_str(text: &'t str) -> Self {
+        Explanations {
+            input: GraphemeIndices::from_str(text),
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<P> Clone for Explanations<'_, P> {
+    fn clone(&self) -> Self {
+        Explanations {
+            input: self.input.clone(),
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<P> Debug for Explanations<'_, P> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Explanations")
+            .field("input", &self.input)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P> DoubleEndedIterator for Explanations<'_, P>
+where
+    P: LegibilityPolicy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.input
+            .next_back()
+            .map(|(index, grapheme)| Explanation::explain::<P>(index, grapheme))
+    }
+}
+
+impl<'t, P> Iterator for Explanations<'t, P>
+where
+    P: LegibilityPolicy,
+{
+    type Item = Explanation<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input
+            .next()
+            .map(|(index, grapheme)| Explanation::explain::<P>(index, grapheme))
+    }
+}
+
+/// An iterator over the byte ranges of runs of illegible grapheme clusters in a string slice.
+///
+/// See [`StrExt::illegible_spans`].
+///
+/// [`StrExt::illegible_spans`]: crate::StrExt::illegible_spans
+pub struct IllegibleSpans<'t, P = Standard> {
+    input: Peekable<GraphemeIndices<'t>>,
+    policy: PhantomData<fn() -> P>,
+}
+
+impl<'t, P> IllegibleSpans<'t, P> {
+    pub(crate) fn from_str(text: &'t str) -> Self {
+        IllegibleSpans {
+            input: GraphemeIndices::from_str(text).peekable(),
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<P> Clone for IllegibleSpans<'_, P> {
+    fn clone(&self) -> Self {
+        IllegibleSpans {
+            input: self.input.clone(),
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<P> Debug for IllegibleSpans<'_, P> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("IllegibleSpans")
+            .field("input", &self.input)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P> Iterator for IllegibleSpans<'_, P>
+where
+    P: LegibilityPolicy,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, grapheme) = self
+            .input
+            .by_ref()
+            .find(|(_, grapheme)| !P::is_legible_grapheme(grapheme))?;
+        let mut end = start + grapheme.as_str().len();
+        while let Some((index, grapheme)) = self
+            .input
+            .next_if(|(_, grapheme)| !P::is_legible_grapheme(grapheme))
+        {
+            end = index + grapheme.as_str().len();
+        }
+        Some(start..end)
+    }
+}
+
+// An iterator over the hidden zero-width code points in a string slice and their byte indices. A
+// zero width joiner is not hidden if it joins code points in an emoji sequence.
+#[derive(Clone, Debug)]
+pub(crate) struct HiddenPoints<'t> {
+    input: GraphemeIndices<'t>,
+    points: CharIndices<'t>,
+    offset: usize,
+    len: usize,
+    is_emoji: bool,
+}
+
+impl<'t> HiddenPoints<'t> {
+    pub(crate) fn from_str(text: &'t str) -> Self {
+        HiddenPoints {
+            input: GraphemeIndices::from_str(text),
+            points: "".char_indices(),
+            offset: 0,
+            len: 0,
+            is_emoji: false,
+        }
+    }
+}
+
+impl Iterator for HiddenPoints<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (len, is_emoji) = (self.len, self.is_emoji);
+            if let Some((index, point)) = self.points.find(|&(index, point)| {
+                property::is_zero_width(point)
+                    && !(is_emoji && point == '\u{200D}' && index + point.len_utf8() < len)
+            }) {
+                return Some((self.offset + index, point));
+            }
+            let (offset, grapheme) = self.input.next()?;
+            self.points = grapheme.as_str().char_indices();
+            self.offset = offset;
+            self.len = grapheme.as_str().len();
+            self.is_emoji = grapheme
+                .as_str()
+                .chars()
+                .find(|&point| !property::is_default_ignorable(point))
+                .is_some_and(property::is_emoji);
+        }
+    }
+}
+
+/// An iterator over the byte ranges of runs of hidden zero-width code points in a string slice.
+///
+/// See [`StrExt::zero_width_runs`].
+///
+/// [`StrExt::zero_width_runs`]: crate::StrExt::zero_width_runs
+#[derive(Clone, Debug)]
+pub struct ZeroWidthRuns<'t> {
+    input: Peekable<HiddenPoints<'t>>,
+}
+
+impl<'t> ZeroWidthRuns<'t> {
+    pub(crate) fn from_str(text: &'t str) -> Self {
+        ZeroWidthRuns {
+            input: HiddenPoints::from_str(text).peekable(),
+        }
+    }
+}
+
+impl Iterator for ZeroWidthRuns<'_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, point) = self.input.next()?;
+        let mut end = start + point.len_utf8();
+        while let Some((_, point)) = self.input.next_if(|&(index, _)| index == end) {
+            end += point.len_utf8();
+        }
+        Some(start..end)
+    }
+}
+
+/// An iterator over the lines of a string slice.
+///
+/// See [`StrExt::split_lines`].
+///
+/// [`StrExt::split_lines`]: crate::StrExt::split_lines
+#[derive(Clone, Debug)]
+pub struct SplitLines<'t> {
+    input: &'t str,
+}
+
+impl<'t> SplitLines<'t> {
+    pub(crate) fn from_str(text: &'t str) -> Self {
+        SplitLines { input: text }
+    }
+}
+
+impl<'t> Iterator for SplitLines<'t> {
+    type Item = &'t str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+        match self
+            .input
+            .char_indices()
+            .find(|&(_, point)| property::is_line_break(point))
+        {
+            Some((index, point)) => {
+                let (line, remainder) = self.input.split_at(index);
+                let remainder = &remainder[point.len_utf8()..];
+                self.input = if point == '\r' {
+                    remainder.strip_prefix('\n').unwrap_or(remainder)
+                }
+                else {
+                    remainder
+                };
+                Some(line)
+            },
+            _ => Some(mem::take(&mut self.input)),
+        }
+    }
+}
@@ -4,16 +4,31 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod property;
+
+pub mod diagnosis;
 pub mod grapheme;
 pub mod iter;
+pub mod policy;
+pub mod precis;
+pub mod security;
+pub mod width;
 
-#[cfg(feature = "alloc")]
-use alloc::borrow::ToOwned;
 use core::error::Error;
 use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::Range;
 use unicode_width::UnicodeWidthStr;
+#[cfg(feature = "alloc")]
+use {alloc::borrow::Cow, alloc::borrow::ToOwned, alloc::string::String};
 
-use crate::iter::{GraphemeIndices, Graphemes};
+use crate::iter::{
+    Explanations, GraphemeIndices, Graphemes, HiddenPoints, IllegibleSpans, SplitLines,
+    ZeroWidthRuns,
+};
+use crate::policy::{LegibilityPolicy, Standard};
+#[cfg(feature = "precis")]
+use crate::precis::{PrecisError, Profile, StringClass};
+use crate::security::RestrictionLevel;
 
 const RUNE_ERROR_MESSAGE: &str =
     "encountered an invalid code point, character, or grapheme cluster";
@@ -23,7 +38,157 @@ pub trait StrExt {
 
     fn grapheme_indices(&self) -> GraphemeIndices<'_>;
 
+    /// Explains the legibility of each grapheme cluster per the [`Standard`] policy.
+    ///
+    /// [`Standard`]: crate::policy::Standard
+    fn explain_legibility(&self) -> Explanations<'_>;
+
+    fn explain_legibility_with<P>(&self) -> Explanations<'_, P>
+    where
+        P: LegibilityPolicy;
+
     fn has_legible_text(&self) -> bool;
+
+    fn has_legible_text_with<P>(&self) -> bool
+    where
+        P: LegibilityPolicy;
+
+    /// Gets the byte range from the start of the first legible grapheme cluster to the end of the
+    /// last legible grapheme cluster per the [`Standard`] policy.
+    ///
+    /// Returns `None` if the string slice has no legible grapheme clusters. See
+    /// [`Grapheme::is_legible_text`].
+    ///
+    /// [`Grapheme::is_legible_text`]: crate::grapheme::Grapheme::is_legible_text
+    /// [`Standard`]: crate::policy::Standard
+    fn legible_span(&self) -> Option<Range<usize>>;
+
+    fn legible_span_with<P>(&self) -> Option<Range<usize>>
+    where
+        P: LegibilityPolicy;
+
+    /// Gets an iterator over the byte ranges of runs of grapheme clusters that are not legible per
+    /// the [`Standard`] policy.
+    ///
+    /// Each range spans as many adjacent illegible grapheme clusters as possible, so ranges are
+    /// separated by legible grapheme clusters. See [`Grapheme::is_legible_text`].
+    ///
+    /// [`Grapheme::is_legible_text`]: crate::grapheme::Grapheme::is_legible_text
+    /// [`Standard`]: crate::policy::Standard
+    fn illegible_spans(&self) -> IllegibleSpans<'_>;
+
+    fn illegible_spans_with<P>(&self) -> IllegibleSpans<'_, P>
+    where
+        P: LegibilityPolicy;
+
+    /// Gets the restriction level of the string slice per UTS39.
+    ///
+    /// Whitespace grapheme clusters are ignored, so that text with more than one word (such as a
+    /// display name) has the same restriction level as its words. See [`RestrictionLevel`].
+    fn restriction_level(&self) -> RestrictionLevel;
+
+    /// Computes the confusable skeleton of the string slice per UTS39.
+    ///
+    /// String slices with the same skeleton are visually confusable, such as `"paypal"` and
+    /// `"p\u{430}ypa1"`. Skeletons are intended for comparisons and should not be displayed.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_skeleton(&self) -> String;
+
+    /// Validates the string slice per the PRECIS string class `C`.
+    ///
+    /// The string slice is valid if each of its code points is valid in `C` and each contextual
+    /// code point satisfies its contextual rule. Unlike [`enforce_precis`], the string slice is
+    /// not mapped.
+    ///
+    /// [`enforce_precis`]: crate::StrExt::enforce_precis
+    #[cfg(feature = "precis")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "precis")))]
+    fn validate_precis<C>(&self) -> Result<(), PrecisError>
+    where
+        C: StringClass;
+
+    /// Enforces the PRECIS profile `R` on the string slice.
+    ///
+    /// The rules of `R` are applied to the string slice until its output is stable and the output
+    /// is then validated per the string class of `R`. For example, enforcing the [`Nickname`]
+    /// profile on `"  Juliet  Capulet "` yields `"Juliet Capulet"`.
+    ///
+    /// [`Nickname`]: crate::precis::Nickname
+    #[cfg(feature = "precis")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "precis")))]
+    fn enforce_precis<R>(&self) -> Result<String, PrecisError>
+    where
+        R: Profile;
+
+    /// Gets an iterator over the byte ranges of runs of hidden zero-width code points in the string
+    /// slice.
+    ///
+    /// Zero-width code points are the zero width space, non-joiner, and joiner, the word joiner,
+    /// the invisible operators and separator (U+2061 to U+2064), the zero width no-break space
+    /// (byte order mark), and the Mongolian vowel separator. These code points are invisible and
+    /// can be interleaved with legible text to hide data, such as watermarks that identify leaked
+    /// documents. A zero width joiner is not hidden if it joins emoji in an emoji sequence. Each
+    /// range spans as many adjacent hidden code points as possible.
+    ///
+    /// Note that the zero width non-joiner and joiner are also used legitimately in some scripts,
+    /// such as Persian and Devanagari.
+    fn zero_width_runs(&self) -> ZeroWidthRuns<'_>;
+
+    /// Estimates the number of bits that could be hidden in the string slice by zero-width code
+    /// points.
+    ///
+    /// Each hidden zero-width code point (see [`zero_width_runs`]) encodes as many bits as are
+    /// needed to distinguish the distinct hidden code points in the string slice, and at least one
+    /// bit. For example, text that interleaves sixteen zero width spaces and non-joiners could
+    /// hide a sixteen bit payload. Returns zero if the string slice has no hidden zero-width code
+    /// points.
+    ///
+    /// [`zero_width_runs`]: crate::StrExt::zero_width_runs
+    fn hidden_payload_bits(&self) -> usize;
+
+    /// Removes hidden zero-width code points from the string slice.
+    ///
+    /// The string slice is borrowed if it has no hidden zero-width code points. Unlike
+    /// [`strip_invisible`], other invisible code points like bidirectional formatting characters
+    /// are not removed. See [`zero_width_runs`].
+    ///
+    /// [`strip_invisible`]: crate::StrExt::strip_invisible
+    /// [`zero_width_runs`]: crate::StrExt::zero_width_runs
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn strip_zero_width(&self) -> Cow<'_, str>;
+
+    /// Splits the string slice into lines.
+    ///
+    /// Unlike [`str::lines`], lines are separated by any mandatory line break per UAX14,
+    /// including line and paragraph separators. As with [`str::lines`], `\r\n` is a single line
+    /// break and a final line break does not begin an empty line.
+    fn split_lines(&self) -> SplitLines<'_>;
+
+    /// Removes invisible code points from the string slice.
+    ///
+    /// Grapheme clusters that consist only of default-ignorable code points, such as zero-width
+    /// spaces, byte order marks, and bidirectional formatting characters, are removed. Within other
+    /// grapheme clusters, default-ignorable code points are removed except for variation
+    /// selectors, conjoining Hangul fillers, and joiners and tags in emoji sequences, so legible
+    /// grapheme clusters are kept intact. The string slice is borrowed if it has no invisible code
+    /// points.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn strip_invisible(&self) -> Cow<'_, str>;
+
+    /// Removes excess combining marks from the grapheme clusters of the string slice.
+    ///
+    /// All but the first `n` combining marks of each grapheme cluster are removed, so stacked
+    /// diacritics (sometimes called "Zalgo" text) are reduced to at most `n` marks per grapheme
+    /// cluster. The string slice is borrowed if no grapheme cluster has more than `n` combining
+    /// marks. See [`Grapheme::combining_mark_count`].
+    ///
+    /// [`Grapheme::combining_mark_count`]: crate::grapheme::Grapheme::combining_mark_count
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn limit_combining_marks(&self, n: usize) -> Cow<'_, str>;
 }
 
 impl StrExt for str {
@@ -35,6 +200,17 @@ impl StrExt for str {
         GraphemeIndices::from_str(self)
     }
 
+    fn explain_legibility(&self) -> Explanations<'_> {
+        Explanations::from_str(self)
+    }
+
+    fn explain_legibility_with<P>(&self) -> Explanations<'_, P>
+    where
+        P: LegibilityPolicy,
+    {
+        Explanations::from_str(self)
+    }
+
     // TODO: The definition of "text" is critical to the purpose of these crates. This must be as
     //       well-defined as possible and documented accordingly. This function implements this
     //       important predicate and so should probably provide this definition in its API
@@ -48,6 +224,139 @@ impl StrExt for str {
                 .count()
                 != 0
     }
+
+    fn has_legible_text_with<P>(&self) -> bool
+    where
+        P: LegibilityPolicy,
+    {
+        P::has_legible_text(self)
+    }
+
+    fn legible_span(&self) -> Option<Range<usize>> {
+        self.legible_span_with::<Standard>()
+    }
+
+    fn legible_span_with<P>(&self) -> Option<Range<usize>>
+    where
+        P: LegibilityPolicy,
+    {
+        let mut graphemes = self
+            .grapheme_indices()
+            .filter(|(_, grapheme)| P::is_legible_grapheme(grapheme));
+        let (start, first) = graphemes.next()?;
+        let (index, last) = graphemes.next_back().unwrap_or((start, first));
+        Some(start..(index + last.as_str().len()))
+    }
+
+    fn illegible_spans(&self) -> IllegibleSpans<'_> {
+        IllegibleSpans::from_str(self)
+    }
+
+    fn illegible_spans_with<P>(&self) -> IllegibleSpans<'_, P>
+    where
+        P: LegibilityPolicy,
+    {
+        IllegibleSpans::from_str(self)
+    }
+
+    fn restriction_level(&self) -> RestrictionLevel {
+        security::restriction_level(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_skeleton(&self) -> String {
+        security::skeleton(self)
+    }
+
+    #[cfg(feature = "precis")]
+    fn validate_precis<C>(&self) -> Result<(), PrecisError>
+    where
+        C: StringClass,
+    {
+        precis::validate::<C>(self)
+    }
+
+    #[cfg(feature = "precis")]
+    fn enforce_precis<R>(&self) -> Result<String, PrecisError>
+    where
+        R: Profile,
+    {
+        precis::enforce::<R>(self)
+    }
+
+    fn zero_width_runs(&self) -> ZeroWidthRuns<'_> {
+        ZeroWidthRuns::from_str(self)
+    }
+
+    fn hidden_payload_bits(&self) -> usize {
+        let (count, distinct) =
+            HiddenPoints::from_str(self).fold((0usize, 0u32), |(count, distinct), (_, point)| {
+                let index = property::ZERO_WIDTH.binary_search(&point).unwrap_or(0);
+                (count + 1, distinct | (1 << index))
+            });
+        let bits = distinct
+            .count_ones()
+            .next_power_of_two()
+            .trailing_zeros()
+            .max(1);
+        count * (bits as usize)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn strip_zero_width(&self) -> Cow<'_, str> {
+        let mut runs = self.zero_width_runs().peekable();
+        if runs.peek().is_none() {
+            return Cow::Borrowed(self);
+        }
+        let mut stripped = String::with_capacity(self.len());
+        let mut start = 0;
+        for run in runs {
+            stripped.push_str(&self[start..run.start]);
+            start = run.end;
+        }
+        stripped.push_str(&self[start..]);
+        Cow::Owned(stripped)
+    }
+
+    fn split_lines(&self) -> SplitLines<'_> {
+        SplitLines::from_str(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn strip_invisible(&self) -> Cow<'_, str> {
+        let mut stripped: Option<String> = None;
+        for (index, grapheme) in self.grapheme_indices() {
+            let is_intact = grapheme.visible_points().count() == grapheme.as_str().chars().count();
+            match stripped {
+                Some(ref mut stripped) => stripped.extend(grapheme.visible_points()),
+                None if !is_intact => {
+                    let mut text = self[..index].to_owned();
+                    text.extend(grapheme.visible_points());
+                    stripped = Some(text);
+                },
+                _ => {},
+            }
+        }
+        stripped.map_or(Cow::Borrowed(self), Cow::Owned)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn limit_combining_marks(&self, n: usize) -> Cow<'_, str> {
+        let mut limited: Option<String> = None;
+        for (index, grapheme) in self.grapheme_indices() {
+            let is_intact = grapheme.combining_mark_count() <= n;
+            match limited {
+                Some(ref mut limited) => limited.extend(grapheme.limited_points(n)),
+                None if !is_intact => {
+                    let mut text = self[..index].to_owned();
+                    text.extend(grapheme.limited_points(n));
+                    limited = Some(text);
+                },
+                _ => {},
+            }
+        }
+        limited.map_or(Cow::Borrowed(self), Cow::Owned)
+    }
 }
 
 // TODO: Implement `From<mitsein::EmptyError<_>>`.
@@ -120,10 +429,147 @@ fn is_grapheme(text: &str) -> bool {
 mod tests {
     extern crate std;
 
+    #[cfg(feature = "alloc")]
+    use alloc::borrow::Cow;
+    use core::ops::Range;
     use rstest::rstest;
 
+    use crate::policy::NonBlank;
     use crate::StrExt as _;
 
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::zero_width_space("a\u{200B}b", "ab")]
+    #[case::byte_order_mark("\u{FEFF}legible", "legible")]
+    #[case::bidi_controls("\u{202E}abc\u{202C}\u{2067}d\u{2069}", "abcd")]
+    #[case::soft_hyphen("leg\u{AD}ible", "legible")]
+    #[case::joiner_outside_emoji("a\u{200D}b\u{200C}c", "abc")]
+    #[case::hangul_filler("\u{3164}\u{FFA0}a", "a")]
+    #[case::only_invisible("\u{200B}\u{2060}", "")]
+    fn strip_invisible_from_str_then_stripped_eq(#[case] text: &str, #[case] expected: &str) {
+        let stripped = text.strip_invisible();
+        assert!(matches!(stripped, Cow::Owned(_)));
+        assert_eq!(stripped, expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::ascii("legible")]
+    #[case::emoji_zwj_sequence("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}")]
+    #[case::emoji_tag_sequence("\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}")]
+    #[case::keycap("1\u{FE0F}\u{20E3}")]
+    #[case::ideographic_variation("\u{845B}\u{E0100}")]
+    #[case::conjoining_hangul("\u{115F}\u{1161}")]
+    fn strip_invisible_from_visible_str_then_borrowed(#[case] text: &str) {
+        assert!(matches!(text.strip_invisible(), Cow::Borrowed(_)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::stacked("a\u{301}\u{302}\u{303}", 1, "a\u{301}")]
+    #[case::stacked_within("Z\u{36B}\u{33F} a\u{300}", 1, "Z\u{36B} a\u{300}")]
+    #[case::none("z\u{36B}\u{33F}\u{344}", 0, "z")]
+    fn limit_combining_marks_of_str_then_limited_eq(
+        #[case] text: &str,
+        #[case] n: usize,
+        #[case] expected: &str,
+    ) {
+        let limited = text.limit_combining_marks(n);
+        assert!(matches!(limited, Cow::Owned(_)));
+        assert_eq!(limited, expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::ascii("legible")]
+    #[case::acute("cafe\u{301}")]
+    #[case::emoji_zwj_sequence("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}")]
+    fn limit_combining_marks_of_str_then_borrowed(#[case] text: &str) {
+        assert!(matches!(text.limit_combining_marks(1), Cow::Borrowed(_)));
+    }
+
+    #[rstest]
+    #[case::legible("legible", &[])]
+    #[case::interleaved("le\u{200B}gi\u{200C}\u{200D}ble", &[(2, 5), (7, 13)])]
+    #[case::word_joiner_and_separator("a\u{2060}\u{2063}b", &[(1, 7)])]
+    #[case::byte_order_mark("\u{FEFF}legible", &[(0, 3)])]
+    #[case::emoji_zwj_sequence("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}", &[])]
+    #[case::joiner_after_emoji("\u{1F600}\u{200D}a", &[(4, 7)])]
+    #[case::bidi_override("\u{202E}legible", &[])]
+    fn zero_width_runs_of_str_then_runs_eq(
+        #[case] text: &str,
+        #[case] expected: &[(usize, usize)],
+    ) {
+        let runs: std::vec::Vec<_> = text
+            .zero_width_runs()
+            .map(|run| (run.start, run.end))
+            .collect();
+        assert_eq!(runs, expected);
+    }
+
+    #[rstest]
+    #[case::legible("legible", 0)]
+    #[case::one("legi\u{200B}ble", 1)]
+    #[case::two_distinct("a\u{200B}b\u{200C}c\u{200B}d", 3)]
+    #[case::four_distinct("a\u{200B}\u{200C}\u{200D}\u{2060}b", 8)]
+    fn hidden_payload_bits_of_str_then_eq(#[case] text: &str, #[case] expected: usize) {
+        assert_eq!(text.hidden_payload_bits(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    fn strip_zero_width_from_str_then_stripped_eq() {
+        let stripped = "le\u{200B}gi\u{200C}\u{200D}ble\u{FEFF}".strip_zero_width();
+        assert!(matches!(stripped, Cow::Owned(_)));
+        assert_eq!(stripped, "legible");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::legible("legible")]
+    #[case::emoji_zwj_sequence("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}")]
+    #[case::soft_hyphen("leg\u{AD}ible")]
+    fn strip_zero_width_from_str_then_borrowed(#[case] text: &str) {
+        assert!(matches!(text.strip_zero_width(), Cow::Borrowed(_)));
+    }
+
+    #[rstest]
+    #[case::empty("", None)]
+    #[case::legible("legible", Some(0..7))]
+    #[case::non_text("\u{200B}legible\u{FEFF}", Some(3..10))]
+    #[case::non_text_within("\u{200B}leg\u{200B}ible", Some(3..13))]
+    #[case::only_non_text("\u{200B}\u{2060}", None)]
+    fn legible_span_of_str_then_span_eq(
+        #[case] text: &str,
+        #[case] expected: Option<Range<usize>>,
+    ) {
+        assert_eq!(text.legible_span(), expected);
+    }
+
+    #[rstest]
+    fn legible_span_of_str_with_policy_then_span_eq() {
+        assert_eq!(" legible ".legible_span(), Some(0..9));
+        assert_eq!(" legible ".legible_span_with::<NonBlank>(), Some(1..8));
+    }
+
+    #[rstest]
+    #[case::empty("", &[])]
+    #[case::legible("legible", &[])]
+    #[case::non_text("\u{200B}legible\u{FEFF}", &[(0, 3), (10, 13)])]
+    #[case::adjacent_non_text("leg\u{200B}\u{2060}ible", &[(3, 9)])]
+    #[case::bidi_override("\u{202E}txt.exe\u{202C}", &[(0, 3), (10, 13)])]
+    #[case::only_non_text("\u{200B}\u{2060}", &[(0, 6)])]
+    fn illegible_spans_of_str_then_spans_eq(
+        #[case] text: &str,
+        #[case] expected: &[(usize, usize)],
+    ) {
+        let spans: std::vec::Vec<_> = text
+            .illegible_spans()
+            .map(|span| (span.start, span.end))
+            .collect();
+        assert_eq!(spans, expected);
+    }
+
     #[rstest]
     fn empty_str_has_no_legible_text() {
         assert!(!"".has_legible_text());
@@ -139,4 +585,18 @@ mod tests {
     fn str_with_private_use_characters_has_legible_text(#[case] text: &str) {
         assert!(text.has_legible_text())
     }
+
+    #[rstest]
+    #[case::empty("", &[])]
+    #[case::one("line", &["line"])]
+    #[case::newline("a\nb", &["a", "b"])]
+    #[case::trailing_newline("a\n", &["a"])]
+    #[case::only_newline("\n", &[""])]
+    #[case::crlf("a\r\n\r\nb", &["a", "", "b"])]
+    #[case::cr("a\rb", &["a", "b"])]
+    #[case::separators("a\u{2028}b\u{2029}c\u{85}d", &["a", "b", "c", "d"])]
+    fn split_lines_of_str_then_lines_eq(#[case] text: &str, #[case] expected: &[&str]) {
+        let lines: std::vec::Vec<_> = text.split_lines().collect();
+        assert_eq!(lines, expected);
+    }
 }
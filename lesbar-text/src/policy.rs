@@ -0,0 +1,374 @@
+//! Policies that define legible text.
+//!
+//! A [`LegibilityPolicy`] determines which grapheme clusters are legible. Text is legible under a
+//! policy if it has at least one grapheme cluster that is legible under that policy and all of its
+//! grapheme clusters are admissible under that policy. The [`Standard`] policy implements the
+//! definition used throughout Lesbar by default.
+
+use core::marker::PhantomData;
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
+
+use crate::diagnosis::{Diagnosis, Illegibility};
+use crate::grapheme::Grapheme;
+use crate::{property, StrExt as _};
+
+/// A predicate that determines which grapheme clusters are legible.
+///
+/// Implementations must be deterministic: the legibility of a given grapheme cluster must never
+/// change. Legible string types rely on this to uphold their invariants. Moreover, implementations
+/// of [`has_legible_text`][`LegibilityPolicy::has_legible_text`] must agree with the grapheme
+/// cluster predicates, because legible string types may examine only some grapheme clusters when
+/// text is edited.
+pub trait LegibilityPolicy {
+    /// Returns `true` if the given grapheme cluster is legible.
+    fn is_legible_grapheme(grapheme: &Grapheme) -> bool;
+
+    /// Returns `true` if the given grapheme cluster may appear in legible text.
+    ///
+    /// Legible text has no inadmissible grapheme clusters. All grapheme clusters are admissible by
+    /// default.
+    fn is_admissible_grapheme(grapheme: &Grapheme) -> bool {
+        let _ = grapheme;
+        true
+    }
+
+    /// Returns `true` if the given string slice has at least one legible grapheme cluster and no
+    /// inadmissible grapheme clusters.
+    fn has_legible_text(text: &str) -> bool {
+        text.graphemes()
+            .try_fold(false, |has_legible, grapheme| {
+                Self::is_admissible_grapheme(grapheme)
+                    .then(|| has_legible || Self::is_legible_grapheme(grapheme))
+            })
+            .unwrap_or(false)
+    }
+
+    /// Diagnoses the given string slice if it has no legible text.
+    ///
+    /// Returns `None` if the string slice is legible.
+    fn diagnose(text: &str) -> Option<Diagnosis> {
+        (!Self::has_legible_text(text)).then(|| Diagnosis::from_illegible_str(text))
+    }
+}
+
+/// A legibility policy for which legible text has a non-zero display width.
+///
+/// Legible string types provide display width APIs with non-zero guarantees for these policies.
+/// Note that this is not the case for [`Lenient`], which considers some zero-width grapheme
+/// clusters legible.
+pub trait NonZeroWidth: LegibilityPolicy {}
+
+/// The default legibility policy.
+///
+/// A grapheme cluster is legible if it has a non-zero display width per UCS and UAX11 or if it is
+/// a private-use character. See [`Grapheme::is_legible_text`].
+#[derive(Clone, Copy, Debug)]
+pub struct Standard;
+
+impl LegibilityPolicy for Standard {
+    fn is_legible_grapheme(grapheme: &Grapheme) -> bool {
+        grapheme.is_legible_text()
+    }
+
+    fn has_legible_text(text: &str) -> bool {
+        text.has_legible_text()
+    }
+}
+
+impl NonZeroWidth for Standard {}
+
+/// A legibility policy that is more conservative than [`Standard`].
+///
+/// A grapheme cluster is legible if it has a non-zero display width and none of its code points
+/// are private-use or unassigned. Private-use characters have no specified presentation, so this
+/// policy does not consider them legible.
+#[derive(Clone, Copy, Debug)]
+pub struct Strict;
+
+impl LegibilityPolicy for Strict {
+    fn is_legible_grapheme(grapheme: &Grapheme) -> bool {
+        grapheme.is_legible_text()
+            && grapheme.chars().all(|point| {
+                !matches!(
+                    point.general_category(),
+                    GeneralCategory::PrivateUse | GeneralCategory::Unassigned,
+                )
+            })
+    }
+}
+
+impl NonZeroWidth for Strict {}
+
+/// A legibility policy that is less conservative than [`Standard`].
+///
+/// A grapheme cluster is legible if it is legible per [`Standard`] or if it has any code point that
+/// is not a control, format, separator (line or paragraph), unassigned, or default-ignorable code
+/// point. For example, this policy considers isolated combining marks legible.
+#[derive(Clone, Copy, Debug)]
+pub struct Lenient;
+
+impl LegibilityPolicy for Lenient {
+    fn is_legible_grapheme(grapheme: &Grapheme) -> bool {
+        grapheme.is_legible_text()
+            || grapheme.chars().any(|point| {
+                !property::is_default_ignorable(point)
+                    && !matches!(
+                        point.general_category(),
+                        GeneralCategory::Control
+                            | GeneralCategory::Format
+                            | GeneralCategory::LineSeparator
+                            | GeneralCategory::ParagraphSeparator
+                            | GeneralCategory::Unassigned,
+                    )
+            })
+    }
+}
+
+/// A legibility policy that rejects blank text.
+///
+/// A grapheme cluster is legible if it is legible per the policy `P` and is not whitespace (see
+/// [`Grapheme::is_whitespace`]). Text that is legible per this policy has some visible content
+/// beyond blank space.
+#[derive(Clone, Copy, Debug)]
+pub struct NonBlank<P = Standard>(PhantomData<fn() -> P>);
+
+impl<P> LegibilityPolicy for NonBlank<P>
+where
+    P: LegibilityPolicy,
+{
+    fn is_legible_grapheme(grapheme: &Grapheme) -> bool {
+        P::is_legible_grapheme(grapheme) && !grapheme.is_whitespace()
+    }
+
+    fn is_admissible_grapheme(grapheme: &Grapheme) -> bool {
+        P::is_admissible_grapheme(grapheme)
+    }
+
+    fn diagnose(text: &str) -> Option<Diagnosis> {
+        P::diagnose(text).or_else(|| {
+            (!Self::has_legible_text(text)).then(|| {
+                Diagnosis::new(
+                    Illegibility::Whitespace,
+                    text.grapheme_indices()
+                        .find(|(_, grapheme)| P::is_legible_grapheme(grapheme))
+                        .map(|(index, grapheme)| index..(index + grapheme.as_str().len())),
+                )
+            })
+        })
+    }
+}
+
+impl<P> NonZeroWidth for NonBlank<P> where P: NonZeroWidth {}
+
+/// A legibility policy that rejects line breaks.
+///
+/// A grapheme cluster is legible if it is legible per the policy `P` and is not a line break (see
+/// [`Grapheme::is_line_break`]). Unlike other policies, line breaks are inadmissible: text that is
+/// legible per this policy has no line breaks at all, including line and paragraph separators.
+#[derive(Clone, Copy, Debug)]
+pub struct SingleLine<P = Standard>(PhantomData<fn() -> P>);
+
+impl<P> LegibilityPolicy for SingleLine<P>
+where
+    P: LegibilityPolicy,
+{
+    fn is_legible_grapheme(grapheme: &Grapheme) -> bool {
+        P::is_legible_grapheme(grapheme) && !grapheme.is_line_break()
+    }
+
+    fn is_admissible_grapheme(grapheme: &Grapheme) -> bool {
+        P::is_admissible_grapheme(grapheme) && !grapheme.is_line_break()
+    }
+
+    fn diagnose(text: &str) -> Option<Diagnosis> {
+        match text
+            .grapheme_indices()
+            .find(|(_, grapheme)| grapheme.is_line_break())
+        {
+            Some((index, grapheme)) => Some(Diagnosis::new(
+                Illegibility::LineBreak,
+                Some(index..(index + grapheme.as_str().len())),
+            )),
+            _ => P::diagnose(text),
+        }
+    }
+}
+
+impl<P> NonZeroWidth for SingleLine<P> where P: NonZeroWidth {}
+
+/// A legibility policy that limits the number of combining marks in grapheme clusters.
+///
+/// A grapheme cluster is legible if it is legible per the policy `P` and has no more than `N`
+/// combining marks (see [`Grapheme::combining_mark_count`]). Like line breaks in [`SingleLine`],
+/// grapheme clusters with more than `N` combining marks are inadmissible: text that is legible per
+/// this policy has no such grapheme clusters at all. This rejects stacked diacritics (sometimes
+/// called "Zalgo" text), which are legible per other policies but disrupt layout and are expensive
+/// to render.
+///
+/// Legitimate text rarely has more than a few combining marks per grapheme cluster, though some
+/// scripts (such as Vietnamese in NFD and Tibetan) stack two or three.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxCombiningMarks<const N: usize, P = Standard>(PhantomData<fn() -> P>);
+
+impl<const N: usize, P> LegibilityPolicy for MaxCombiningMarks<N, P>
+where
+    P: LegibilityPolicy,
+{
+    fn is_legible_grapheme(grapheme: &Grapheme) -> bool {
+        P::is_legible_grapheme(grapheme) && grapheme.combining_mark_count() <= N
+    }
+
+    fn is_admissible_grapheme(grapheme: &Grapheme) -> bool {
+        P::is_admissible_grapheme(grapheme) && grapheme.combining_mark_count() <= N
+    }
+
+    fn diagnose(text: &str) -> Option<Diagnosis> {
+        match text
+            .grapheme_indices()
+            .find(|(_, grapheme)| grapheme.combining_mark_count() > N)
+        {
+            Some((index, grapheme)) => Some(Diagnosis::new(
+                Illegibility::CombiningMarkLimit,
+                Some(index..(index + grapheme.as_str().len())),
+            )),
+            _ => P::diagnose(text),
+        }
+    }
+}
+
+impl<const N: usize, P> NonZeroWidth for MaxCombiningMarks<N, P> where P: NonZeroWidth {}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+
+    use crate::diagnosis::Illegibility;
+    use crate::grapheme::Grapheme;
+    use crate::policy::{
+        LegibilityPolicy, Lenient, MaxCombiningMarks, NonBlank, SingleLine, Standard, Strict,
+    };
+
+    #[rstest]
+    #[case::latin("legible")]
+    #[case::japanese("練習")]
+    #[case::whitespace(" ")]
+    fn str_has_legible_text_per_each_policy(#[case] text: &str) {
+        assert!(Standard::has_legible_text(text));
+        assert!(Strict::has_legible_text(text));
+        assert!(Lenient::has_legible_text(text));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::zwsp("\u{200B}")]
+    #[case::bom("\u{FEFF}")]
+    #[case::variation_selector("\u{FE0F}")]
+    fn str_has_no_legible_text_per_each_policy(#[case] text: &str) {
+        assert!(!Standard::has_legible_text(text));
+        assert!(!Strict::has_legible_text(text));
+        assert!(!Lenient::has_legible_text(text));
+    }
+
+    #[rstest]
+    #[case::one_private_use("\u{E064}")]
+    #[case::many_private_use("\u{E000}\u{F0000}\u{10FFFD}")]
+    fn str_with_only_private_use_characters_has_no_strictly_legible_text(#[case] text: &str) {
+        assert!(Standard::has_legible_text(text));
+        assert!(!Strict::has_legible_text(text));
+    }
+
+    #[rstest]
+    #[case::acute("\u{301}")]
+    #[case::tilde("\u{303}")]
+    fn str_with_only_combining_marks_has_only_leniently_legible_text(#[case] text: &str) {
+        assert!(!Standard::has_legible_text(text));
+        assert!(Lenient::has_legible_text(text));
+    }
+
+    #[rstest]
+    #[case::space(" ")]
+    #[case::spaces("   ")]
+    #[case::tab_and_newline("\t\n")]
+    #[case::ideographic_space("\u{3000}")]
+    #[case::space_and_zwsp(" \u{200B} ")]
+    fn str_with_only_whitespace_has_no_non_blank_text(#[case] text: &str) {
+        assert!(Standard::has_legible_text(text));
+        assert!(!NonBlank::<Standard>::has_legible_text(text));
+        assert_eq!(
+            NonBlank::<Standard>::diagnose(text).map(|diagnosis| diagnosis.illegibility()),
+            Some(Illegibility::Whitespace),
+        );
+    }
+
+    #[rstest]
+    #[case::letter("a")]
+    #[case::padded_letter("  a  ")]
+    #[case::nbsp_and_letter("\u{A0}a")]
+    fn str_with_non_whitespace_has_non_blank_text(#[case] text: &str) {
+        assert!(NonBlank::<Standard>::has_legible_text(text));
+    }
+
+    #[rstest]
+    #[case::newline("legible\n", 7..8)]
+    #[case::crlf("a\r\nb", 1..3)]
+    #[case::form_feed("\u{C}", 0..1)]
+    #[case::next_line("a\u{85}", 1..3)]
+    #[case::line_separator("\u{2028}legible", 0..3)]
+    #[case::paragraph_separator("legible\u{2029}", 7..10)]
+    fn str_with_line_break_has_no_single_line_text(
+        #[case] text: &str,
+        #[case] span: core::ops::Range<usize>,
+    ) {
+        assert!(!SingleLine::<Standard>::has_legible_text(text));
+        let diagnosis = SingleLine::<Standard>::diagnose(text).unwrap();
+        assert_eq!(diagnosis.illegibility(), Illegibility::LineBreak);
+        assert_eq!(diagnosis.span(), Some(span));
+    }
+
+    #[rstest]
+    fn str_with_only_whitespace_and_line_break_has_no_non_blank_single_line_text() {
+        assert!(!NonBlank::<SingleLine>::has_legible_text("a\n"));
+        assert!(!SingleLine::<NonBlank>::has_legible_text("a\n"));
+        assert!(NonBlank::<SingleLine>::has_legible_text(" a\t"));
+        assert_eq!(
+            NonBlank::<SingleLine>::diagnose(" \t").map(|diagnosis| diagnosis.illegibility()),
+            Some(Illegibility::Whitespace),
+        );
+    }
+
+    #[rstest]
+    #[case::letter("a", 0)]
+    #[case::acute("e\u{301}", 1)]
+    #[case::stacked("a\u{301}\u{302}\u{303}", 3)]
+    #[case::enclosing("1\u{20DD}", 1)]
+    #[case::emoji("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}", 0)]
+    #[case::keycap("1\u{FE0F}\u{20E3}", 1)]
+    fn combining_mark_count_of_grapheme_then_eq(#[case] text: &str, #[case] expected: usize) {
+        let grapheme = Grapheme::try_from_str(text).unwrap();
+        assert_eq!(grapheme.combining_mark_count(), expected);
+    }
+
+    #[rstest]
+    #[case::no_marks("legible")]
+    #[case::some_marks("cafe\u{301}")]
+    #[case::limit("a\u{301}\u{302}")]
+    fn str_with_few_combining_marks_has_limited_text(#[case] text: &str) {
+        assert!(MaxCombiningMarks::<2>::has_legible_text(text));
+    }
+
+    #[rstest]
+    #[case::stacked("a\u{301}\u{302}\u{303}", 0..7)]
+    #[case::stacked_within("legible z\u{36B}\u{33F}\u{344}\u{300}", 8..17)]
+    fn str_with_many_combining_marks_has_no_limited_text(
+        #[case] text: &str,
+        #[case] span: core::ops::Range<usize>,
+    ) {
+        assert!(Standard::has_legible_text(text));
+        assert!(!MaxCombiningMarks::<2>::has_legible_text(text));
+        let diagnosis = MaxCombiningMarks::<2>::diagnose(text).unwrap();
+        assert_eq!(diagnosis.illegibility(), Illegibility::CombiningMarkLimit);
+        assert_eq!(diagnosis.span(), Some(span));
+    }
+}
@@ -0,0 +1,667 @@
+//! String classes and profiles per PRECIS (RFC 8264).
+//!
+//! PRECIS enforces internationalized strings in protocols, such as usernames, passwords, and
+//! nicknames. A [`StringClass`] determines which code points are valid in a string and a
+//! [`Profile`] determines how a string is mapped before it is validated per its string class. This
+//! module implements the [`IdentifierClass`] and [`FreeformClass`] string classes, the
+//! [`UsernameCaseMapped`], [`UsernameCasePreserved`], and [`OpaqueString`] profiles per RFC 8265,
+//! and the [`Nickname`] profile per RFC 8266.
+
+#![cfg(feature = "precis")]
+#![cfg_attr(docsrs, doc(cfg(feature = "precis")))]
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use unicode_bidi::BidiClass;
+use unicode_joining_type::JoiningType;
+use unicode_normalization::UnicodeNormalization as _;
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory as _};
+use unicode_script::{Script, UnicodeScript as _};
+
+use crate::property;
+
+// The maximum number of times that the rules of a profile are applied to a string until its output
+// is stable. See RFC 8264 section 7.
+const MAX_ENFORCEMENTS: usize = 4;
+
+const VIRAMA: u8 = 9;
+
+/// The derived property of a code point per PRECIS (RFC 8264 section 8).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DerivedProperty {
+    /// `PVALID`: the code point is valid in all string classes.
+    Valid,
+    /// `CONTEXTJ`: the code point is a join control that is valid only in some contexts.
+    ContextJ,
+    /// `CONTEXTO`: the code point is valid only in some contexts.
+    ContextO,
+    /// `ID_DIS` or `FREE_PVAL`: the code point is valid in the [`FreeformClass`] but not the
+    /// [`IdentifierClass`], such as spaces, symbols, and code points with compatibility
+    /// decompositions.
+    Freeform,
+    /// `DISALLOWED`: the code point is not valid in any string class.
+    Disallowed,
+    /// `UNASSIGNED`: the code point is not assigned.
+    Unassigned,
+}
+
+impl DerivedProperty {
+    /// Derives the property of a code point.
+    pub fn classify(point: char) -> Self {
+        let category = point.general_category();
+        if let Some(property) = exception(point) {
+            property
+        }
+        else if category == GeneralCategory::Unassigned && !is_noncharacter(point) {
+            DerivedProperty::Unassigned
+        }
+        else if matches!(point, '\u{21}'..='\u{7E}') {
+            DerivedProperty::Valid
+        }
+        else if matches!(point, '\u{200C}' | '\u{200D}') {
+            DerivedProperty::ContextJ
+        }
+        else if is_old_hangul_jamo(point)
+            || property::is_default_ignorable(point)
+            || is_noncharacter(point)
+            || category == GeneralCategory::Control
+        {
+            DerivedProperty::Disallowed
+        }
+        else if point.nfkc().ne(core::iter::once(point)) {
+            DerivedProperty::Freeform
+        }
+        else {
+            match category {
+                GeneralCategory::LowercaseLetter
+                | GeneralCategory::UppercaseLetter
+                | GeneralCategory::OtherLetter
+                | GeneralCategory::DecimalNumber
+                | GeneralCategory::ModifierLetter
+                | GeneralCategory::NonspacingMark
+                | GeneralCategory::SpacingMark => DerivedProperty::Valid,
+                GeneralCategory::TitlecaseLetter
+                | GeneralCategory::LetterNumber
+                | GeneralCategory::OtherNumber
+                | GeneralCategory::EnclosingMark
+                | GeneralCategory::SpaceSeparator
+                | GeneralCategory::MathSymbol
+                | GeneralCategory::CurrencySymbol
+                | GeneralCategory::ModifierSymbol
+                | GeneralCategory::OtherSymbol
+                | GeneralCategory::ConnectorPunctuation
+                | GeneralCategory::DashPunctuation
+                | GeneralCategory::OpenPunctuation
+                | GeneralCategory::ClosePunctuation
+                | GeneralCategory::InitialPunctuation
+                | GeneralCategory::FinalPunctuation
+                | GeneralCategory::OtherPunctuation => DerivedProperty::Freeform,
+                _ => DerivedProperty::Disallowed,
+            }
+        }
+    }
+}
+
+/// A PRECIS string class, which determines the code points that are valid in a string.
+pub trait StringClass {
+    /// Returns `true` if code points with the given derived property are valid in the string
+    /// class.
+    ///
+    /// Contextual code points must also satisfy their contextual rules per RFC 5892 appendix A.
+    fn is_valid(property: DerivedProperty) -> bool;
+}
+
+/// The `IdentifierClass` per RFC 8264 section 4.2.
+///
+/// Identifiers consist of letters, digits, and printable ASCII. Spaces, symbols, and code points
+/// with compatibility decompositions are disallowed.
+#[derive(Clone, Copy, Debug)]
+pub struct IdentifierClass;
+
+impl StringClass for IdentifierClass {
+    fn is_valid(property: DerivedProperty) -> bool {
+        matches!(
+            property,
+            DerivedProperty::Valid | DerivedProperty::ContextJ | DerivedProperty::ContextO
+        )
+    }
+}
+
+/// The `FreeformClass` per RFC 8264 section 4.3.
+///
+/// Freeform strings may also have spaces, symbols, punctuation, and code points with compatibility
+/// decompositions.
+#[derive(Clone, Copy, Debug)]
+pub struct FreeformClass;
+
+impl StringClass for FreeformClass {
+    fn is_valid(property: DerivedProperty) -> bool {
+        matches!(
+            property,
+            DerivedProperty::Valid
+                | DerivedProperty::ContextJ
+                | DerivedProperty::ContextO
+                | DerivedProperty::Freeform
+        )
+    }
+}
+
+/// A PRECIS profile, which determines how strings in a [`StringClass`] are enforced and compared.
+///
+/// Implementations must be deterministic. Enforcement applies the rules of a profile until its
+/// output is stable, so mappings need not be idempotent, but they must converge.
+pub trait Profile {
+    type Class: StringClass;
+
+    /// Applies the width mapping, additional mapping, case mapping, and normalization rules of the
+    /// profile to a string.
+    fn map(text: &str) -> String;
+
+    /// Returns `true` if the Bidi Rule (RFC 5893) applies to strings with right-to-left
+    /// characters.
+    fn has_bidi_rule() -> bool {
+        false
+    }
+
+    /// Maps an enforced string for comparison.
+    ///
+    /// Enforced strings are equivalent per the profile if these mappings are equal. Enforced
+    /// strings are compared as is by default.
+    fn to_comparable(text: &str) -> Cow<'_, str> {
+        Cow::Borrowed(text)
+    }
+}
+
+/// The `UsernameCaseMapped` profile per RFC 8265 section 3.3.
+///
+/// Fullwidth and halfwidth code points are mapped to their decompositions, code points are mapped
+/// to lowercase, and the string is normalized into NFC. For example, `"Juliet"` is enforced as
+/// `"juliet"`.
+#[derive(Clone, Copy, Debug)]
+pub struct UsernameCaseMapped;
+
+impl Profile for UsernameCaseMapped {
+    type Class = IdentifierClass;
+
+    fn map(text: &str) -> String {
+        map_width(text).to_lowercase().nfc().collect()
+    }
+
+    fn has_bidi_rule() -> bool {
+        true
+    }
+}
+
+/// The `UsernameCasePreserved` profile per RFC 8265 section 3.4.
+///
+/// Fullwidth and halfwidth code points are mapped to their decompositions and the string is
+/// normalized into NFC. Unlike [`UsernameCaseMapped`], case is preserved.
+#[derive(Clone, Copy, Debug)]
+pub struct UsernameCasePreserved;
+
+impl Profile for UsernameCasePreserved {
+    type Class = IdentifierClass;
+
+    fn map(text: &str) -> String {
+        map_width(text).nfc().collect()
+    }
+
+    fn has_bidi_rule() -> bool {
+        true
+    }
+}
+
+/// The `OpaqueString` profile per RFC 8265 section 4.2.
+///
+/// Non-ASCII spaces are mapped to U+0020 SPACE and the string is normalized into NFC. This profile
+/// is intended for passwords and other secrets.
+#[derive(Clone, Copy, Debug)]
+pub struct OpaqueString;
+
+impl Profile for OpaqueString {
+    type Class = FreeformClass;
+
+    fn map(text: &str) -> String {
+        map_spaces(text).nfc().collect()
+    }
+}
+
+/// The `Nickname` profile per RFC 8266.
+///
+/// Non-ASCII spaces are mapped to U+0020 SPACE, leading and trailing spaces are removed, runs of
+/// spaces are collapsed into one space, and the string is normalized into NFKC. For example,
+/// `"  Juliet  Capulet "` is enforced as `"Juliet Capulet"`. Nicknames preserve case, but are
+/// compared without regard to case.
+#[derive(Clone, Copy, Debug)]
+pub struct Nickname;
+
+impl Profile for Nickname {
+    type Class = FreeformClass;
+
+    fn map(text: &str) -> String {
+        let spaced = map_spaces(text);
+        let mut collapsed = String::with_capacity(spaced.len());
+        for word in spaced.split(' ').filter(|word| !word.is_empty()) {
+            if !collapsed.is_empty() {
+                collapsed.push(' ');
+            }
+            collapsed.push_str(word);
+        }
+        collapsed.nfkc().collect()
+    }
+
+    fn to_comparable(text: &str) -> Cow<'_, str> {
+        Cow::Owned(text.to_lowercase().nfkc().collect())
+    }
+}
+
+/// Describes how a string violates a PRECIS string class or profile.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Violation {
+    /// The string is empty.
+    Empty,
+    /// A code point is not valid in the string class.
+    Disallowed,
+    /// A code point is not assigned.
+    Unassigned,
+    /// A contextual code point does not satisfy its contextual rule, such as a zero width joiner
+    /// that does not follow a virama.
+    Context,
+    /// The string has right-to-left characters and does not satisfy the Bidi Rule (RFC 5893).
+    BidiRule,
+    /// The rules of the profile do not converge on a stable string.
+    Unstable,
+}
+
+impl Display for Violation {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Violation::Empty => "empty",
+            Violation::Disallowed => "disallowed code point",
+            Violation::Unassigned => "unassigned code point",
+            Violation::Context => "contextual rule not satisfied",
+            Violation::BidiRule => "bidi rule not satisfied",
+            Violation::Unstable => "unstable mapping",
+        })
+    }
+}
+
+/// An error that describes how a string violates a PRECIS string class or profile.
+///
+/// See [`StrExt::enforce_precis`] and [`StrExt::validate_precis`].
+///
+/// [`StrExt::enforce_precis`]: crate::StrExt::enforce_precis
+/// [`StrExt::validate_precis`]: crate::StrExt::validate_precis
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PrecisError {
+    violation: Violation,
+    index: Option<usize>,
+}
+
+impl PrecisError {
+    fn from_violation(violation: Violation) -> Self {
+        PrecisError {
+            violation,
+            index: None,
+        }
+    }
+
+    fn from_violation_at(violation: Violation, index: usize) -> Self {
+        PrecisError {
+            violation,
+            index: Some(index),
+        }
+    }
+
+    pub fn violation(&self) -> Violation {
+        self.violation
+    }
+
+    /// Gets the byte index of the offending code point, if any.
+    ///
+    /// When a profile is enforced, this index is into the mapped string rather than the input.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+impl Display for PrecisError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "PRECIS violation: {}", self.violation)?;
+        if let Some(index) = self.index {
+            write!(formatter, " at index {index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for PrecisError {}
+
+// Validates a string per the string class `C`, including the contextual rules of RFC 5892
+// appendix A.
+pub(crate) fn validate<C>(text: &str) -> Result<(), PrecisError>
+where
+    C: StringClass,
+{
+    for (index, point) in text.char_indices() {
+        let property = DerivedProperty::classify(point);
+        if !C::is_valid(property) {
+            let violation = if property == DerivedProperty::Unassigned {
+                Violation::Unassigned
+            }
+            else {
+                Violation::Disallowed
+            };
+            return Err(PrecisError::from_violation_at(violation, index));
+        }
+        if matches!(
+            property,
+            DerivedProperty::ContextJ | DerivedProperty::ContextO
+        ) && !is_context_satisfied(text, index, point)
+        {
+            return Err(PrecisError::from_violation_at(Violation::Context, index));
+        }
+    }
+    Ok(())
+}
+
+// Enforces the profile `R` on a string per RFC 8264 section 7: the rules of the profile are applied
+// until the output is stable and the output is then validated.
+pub(crate) fn enforce<R>(text: &str) -> Result<String, PrecisError>
+where
+    R: Profile,
+{
+    let mut output = R::map(text);
+    for _ in 1..MAX_ENFORCEMENTS {
+        let next = R::map(&output);
+        if next == output {
+            if output.is_empty() {
+                return Err(PrecisError::from_violation(Violation::Empty));
+            }
+            validate::<R::Class>(&output)?;
+            if R::has_bidi_rule() && !is_bidi_rule_satisfied(&output) {
+                return Err(PrecisError::from_violation(Violation::BidiRule));
+            }
+            return Ok(output);
+        }
+        output = next;
+    }
+    Err(PrecisError::from_violation(Violation::Unstable))
+}
+
+// Gets the derived property of exceptional code points per RFC 5892 section 2.6.
+fn exception(point: char) -> Option<DerivedProperty> {
+    match point {
+        '\u{DF}' | '\u{3C2}' | '\u{6FD}' | '\u{6FE}' | '\u{F0B}' | '\u{3007}' => {
+            Some(DerivedProperty::Valid)
+        },
+        '\u{B7}'
+        | '\u{375}'
+        | '\u{5F3}'
+        | '\u{5F4}'
+        | '\u{30FB}'
+        | '\u{660}'..='\u{669}'
+        | '\u{6F0}'..='\u{6F9}' => Some(DerivedProperty::ContextO),
+        '\u{640}' | '\u{7FA}' | '\u{302E}' | '\u{302F}' | '\u{3031}'..='\u{3035}' | '\u{303B}' => {
+            Some(DerivedProperty::Disallowed)
+        },
+        _ => None,
+    }
+}
+
+// Returns `true` if the code point is a conjoining Hangul jamo (`Hangul_Syllable_Type` `L`, `V`, or
+// `T`). Unassigned code points in these blocks are excluded before this predicate is used.
+fn is_old_hangul_jamo(point: char) -> bool {
+    matches!(
+        point,
+        '\u{1100}'..='\u{11FF}' | '\u{A960}'..='\u{A97F}' | '\u{D7B0}'..='\u{D7FF}'
+    )
+}
+
+fn is_noncharacter(point: char) -> bool {
+    matches!(point, '\u{FDD0}'..='\u{FDEF}') || (u32::from(point) & 0xFFFE) == 0xFFFE
+}
+
+// Returns `true` if the code point has a `<wide>` or `<narrow>` decomposition.
+fn is_width_variant(point: char) -> bool {
+    matches!(point, '\u{20A9}' | '\u{3000}' | '\u{FF01}'..='\u{FFEE}')
+}
+
+// Maps fullwidth and halfwidth code points to their decompositions.
+fn map_width(text: &str) -> String {
+    let mut mapped = String::with_capacity(text.len());
+    for point in text.chars() {
+        if is_width_variant(point) {
+            unicode_normalization::char::decompose_compatible(point, |point| mapped.push(point));
+        }
+        else {
+            mapped.push(point);
+        }
+    }
+    mapped
+}
+
+// Maps non-ASCII spaces to U+0020 SPACE.
+fn map_spaces(text: &str) -> String {
+    text.chars()
+        .map(|point| {
+            if point.general_category() == GeneralCategory::SpaceSeparator {
+                ' '
+            }
+            else {
+                point
+            }
+        })
+        .collect()
+}
+
+// Returns `true` if the contextual code point at the given byte index satisfies its contextual
+// rule per RFC 5892 appendix A.
+fn is_context_satisfied(text: &str, index: usize, point: char) -> bool {
+    let mut before = text[..index].chars().rev();
+    let mut after = text[(index + point.len_utf8())..].chars();
+    let is_after_virama = |mut before: core::iter::Rev<core::str::Chars<'_>>| {
+        before.next().is_some_and(|point| {
+            unicode_normalization::char::canonical_combining_class(point) == VIRAMA
+        })
+    };
+    match point {
+        '\u{200C}' => {
+            let left = [JoiningType::LeftJoining, JoiningType::DualJoining];
+            let right = [JoiningType::RightJoining, JoiningType::DualJoining];
+            is_after_virama(before.clone())
+                || (is_joined(before, &left) && is_joined(after, &right))
+        },
+        '\u{200D}' => is_after_virama(before),
+        '\u{B7}' => before.next() == Some('l') && after.next() == Some('l'),
+        '\u{375}' => after
+            .next()
+            .is_some_and(|point| point.script() == Script::Greek),
+        '\u{5F3}' | '\u{5F4}' => before
+            .next()
+            .is_some_and(|point| point.script() == Script::Hebrew),
+        '\u{30FB}' => text.chars().any(|point| {
+            matches!(
+                point.script(),
+                Script::Han | Script::Hiragana | Script::Katakana
+            )
+        }),
+        '\u{660}'..='\u{669}' => !text
+            .chars()
+            .any(|point| matches!(point, '\u{6F0}'..='\u{6F9}')),
+        '\u{6F0}'..='\u{6F9}' => !text
+            .chars()
+            .any(|point| matches!(point, '\u{660}'..='\u{669}')),
+        _ => false,
+    }
+}
+
+// Returns `true` if the first code point that is not transparent has one of the given joining
+// types.
+fn is_joined(points: impl Iterator<Item = char>, types: &[JoiningType]) -> bool {
+    points
+        .map(unicode_joining_type::get_joining_type)
+        .find(|joining| *joining != JoiningType::Transparent)
+        .is_some_and(|joining| types.contains(&joining))
+}
+
+// Returns `true` if the string satisfies the Bidi Rule per RFC 5893 section 2. Strings with no
+// right-to-left characters (`R`, `AL`, or `AN`) satisfy the rule.
+fn is_bidi_rule_satisfied(text: &str) -> bool {
+    use BidiClass::{AL, AN, BN, CS, EN, ES, ET, L, NSM, ON, R};
+
+    let classes = || text.chars().map(unicode_bidi::bidi_class);
+    if !classes().any(|class| matches!(class, R | AL | AN)) {
+        return true;
+    }
+    let last = classes().rev().find(|class| *class != NSM);
+    match classes().next() {
+        Some(R | AL) => {
+            classes().all(|class| matches!(class, R | AL | AN | EN | ES | CS | ET | ON | BN | NSM))
+                && matches!(last, Some(R | AL | EN | AN))
+                && !(classes().any(|class| class == EN) && classes().any(|class| class == AN))
+        },
+        Some(L) => {
+            classes().all(|class| matches!(class, L | EN | ES | CS | ET | ON | BN | NSM))
+                && matches!(last, Some(L | EN))
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+
+    use crate::precis::{
+        DerivedProperty, FreeformClass, IdentifierClass, Nickname, OpaqueString, Profile as _,
+        UsernameCaseMapped, UsernameCasePreserved, Violation,
+    };
+    use crate::StrExt as _;
+
+    #[rstest]
+    #[case::ascii_letter('a', DerivedProperty::Valid)]
+    #[case::ascii_symbol('@', DerivedProperty::Valid)]
+    #[case::combining_mark('\u{301}', DerivedProperty::Valid)]
+    #[case::sharp_s('\u{DF}', DerivedProperty::Valid)]
+    #[case::space(' ', DerivedProperty::Freeform)]
+    #[case::fullwidth('\u{FF21}', DerivedProperty::Freeform)]
+    #[case::roman_numeral('\u{2163}', DerivedProperty::Freeform)]
+    #[case::zero_width_joiner('\u{200D}', DerivedProperty::ContextJ)]
+    #[case::middle_dot('\u{B7}', DerivedProperty::ContextO)]
+    #[case::null('\u{0}', DerivedProperty::Disallowed)]
+    #[case::tatweel('\u{640}', DerivedProperty::Disallowed)]
+    #[case::old_hangul_jamo('\u{1100}', DerivedProperty::Disallowed)]
+    #[case::private_use('\u{E000}', DerivedProperty::Disallowed)]
+    #[case::unassigned('\u{378}', DerivedProperty::Unassigned)]
+    fn classify_char_then_derived_property_eq(
+        #[case] point: char,
+        #[case] expected: DerivedProperty,
+    ) {
+        assert_eq!(DerivedProperty::classify(point), expected);
+    }
+
+    #[rstest]
+    #[case::lowercase("juliet", "juliet")]
+    #[case::uppercase("Juliet", "juliet")]
+    #[case::fullwidth("\u{FF2A}uliet", "juliet")]
+    #[case::address("juliet@example.com", "juliet@example.com")]
+    #[case::final_sigma("\u{3A3}\u{391}\u{3A3}", "\u{3C3}\u{3B1}\u{3C2}")]
+    #[case::right_to_left("\u{5D0}1", "\u{5D0}1")]
+    fn enforce_username_case_mapped_on_str_then_eq(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(
+            text.enforce_precis::<UsernameCaseMapped>().unwrap(),
+            expected,
+        );
+    }
+
+    #[rstest]
+    #[case::uppercase("Juliet", "Juliet")]
+    #[case::fullwidth("\u{FF2A}uliet", "Juliet")]
+    fn enforce_username_case_preserved_on_str_then_eq(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(
+            text.enforce_precis::<UsernameCasePreserved>().unwrap(),
+            expected,
+        );
+    }
+
+    #[rstest]
+    #[case::empty("", Violation::Empty, None)]
+    #[case::space("juliet capulet", Violation::Disallowed, Some(6))]
+    #[case::roman_numeral("\u{2163}", Violation::Disallowed, Some(0))]
+    #[case::unassigned("\u{378}", Violation::Unassigned, Some(0))]
+    #[case::bidi_rule("a\u{5D0}", Violation::BidiRule, None)]
+    fn enforce_username_case_mapped_on_str_then_violation_eq(
+        #[case] text: &str,
+        #[case] violation: Violation,
+        #[case] index: Option<usize>,
+    ) {
+        let error = text.enforce_precis::<UsernameCaseMapped>().unwrap_err();
+        assert_eq!(error.violation(), violation);
+        assert_eq!(error.index(), index);
+    }
+
+    #[rstest]
+    #[case::words("correct horse battery staple", "correct horse battery staple")]
+    #[case::no_break_space("Correct\u{A0}Horse", "Correct Horse")]
+    #[case::roman_numeral("\u{2163}", "\u{2163}")]
+    fn enforce_opaque_string_on_str_then_eq(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(text.enforce_precis::<OpaqueString>().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::words("Juliet Capulet", "Juliet Capulet")]
+    #[case::spaces("  Juliet   Capulet ", "Juliet Capulet")]
+    #[case::non_ascii_spaces("Juliet\u{A0}\u{3000}Capulet", "Juliet Capulet")]
+    #[case::roman_numeral("Henry \u{2163}", "Henry IV")]
+    fn enforce_nickname_on_str_then_eq(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(text.enforce_precis::<Nickname>().unwrap(), expected);
+    }
+
+    #[rstest]
+    fn enforce_nickname_on_spaces_then_empty_violation() {
+        let error = "   ".enforce_precis::<Nickname>().unwrap_err();
+        assert_eq!(error.violation(), Violation::Empty);
+    }
+
+    #[rstest]
+    fn compare_nicknames_then_caseless_eq() {
+        let nickname = "Juliet Capulet".enforce_precis::<Nickname>().unwrap();
+        let other = " juliet  CAPULET".enforce_precis::<Nickname>().unwrap();
+        assert_ne!(nickname, other);
+        assert_eq!(
+            Nickname::to_comparable(&nickname),
+            Nickname::to_comparable(&other),
+        );
+    }
+
+    #[rstest]
+    #[case::middle_dot("l\u{B7}l", true)]
+    #[case::misplaced_middle_dot("a\u{B7}b", false)]
+    #[case::keraia("\u{375}\u{3B1}", true)]
+    #[case::misplaced_keraia("\u{375}a", false)]
+    #[case::zero_width_joiner_after_virama("\u{915}\u{94D}\u{200D}", true)]
+    #[case::zero_width_joiner("a\u{200D}b", false)]
+    #[case::arabic_indic_digits("\u{660}\u{661}", true)]
+    #[case::mixed_arabic_indic_digits("\u{660}\u{6F1}", false)]
+    fn validate_str_with_contextual_rule_then_eq(#[case] text: &str, #[case] expected: bool) {
+        let result = text.validate_precis::<IdentifierClass>();
+        assert_eq!(result.is_ok(), expected);
+        if let Err(error) = result {
+            assert_eq!(error.violation(), Violation::Context);
+        }
+    }
+
+    #[rstest]
+    fn validate_str_with_space_then_identifier_error_and_freeform_ok() {
+        assert!("juliet capulet"
+            .validate_precis::<IdentifierClass>()
+            .is_err());
+        assert!("juliet capulet".validate_precis::<FreeformClass>().is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+use core::cmp::Ordering;
+use unicode_properties::{GeneralCategory, UnicodeEmoji as _, UnicodeGeneralCategory as _};
+
+// Code points with the `Default_Ignorable_Code_Point` property per `DerivedCoreProperties.txt`.
+// These ranges are sorted and disjoint.
+const DEFAULT_IGNORABLE: &[(char, char)] = &[
+    ('\u{00AD}', '\u{00AD}'),
+    ('\u{034F}', '\u{034F}'),
+    ('\u{061C}', '\u{061C}'),
+    ('\u{115F}', '\u{1160}'),
+    ('\u{17B4}', '\u{17B5}'),
+    ('\u{180B}', '\u{180F}'),
+    ('\u{200B}', '\u{200F}'),
+    ('\u{202A}', '\u{202E}'),
+    ('\u{2060}', '\u{206F}'),
+    ('\u{3164}', '\u{3164}'),
+    ('\u{FE00}', '\u{FE0F}'),
+    ('\u{FEFF}', '\u{FEFF}'),
+    ('\u{FFA0}', '\u{FFA0}'),
+    ('\u{FFF0}', '\u{FFF8}'),
+    ('\u{1BCA0}', '\u{1BCA3}'),
+    ('\u{1D173}', '\u{1D17A}'),
+    ('\u{E0000}', '\u{E0FFF}'),
+];
+
+// Zero-width code points that are commonly used to hide data in text, such as watermarks: the
+// Mongolian vowel separator, zero width space, non-joiner, and joiner, word joiner, invisible
+// operators and separator, and zero width no-break space (byte order mark). These are sorted.
+pub const ZERO_WIDTH: &[char] = &[
+    '\u{180E}', '\u{200B}', '\u{200C}', '\u{200D}', '\u{2060}', '\u{2061}', '\u{2062}', '\u{2063}',
+    '\u{2064}', '\u{FEFF}',
+];
+
+pub fn is_default_ignorable(point: char) -> bool {
+    DEFAULT_IGNORABLE
+        .binary_search_by(|&(start, end)| {
+            if point < start {
+                Ordering::Greater
+            }
+            else if point > end {
+                Ordering::Less
+            }
+            else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// Returns `true` if the code point is a combining mark (general category `Mn`, `Mc`, or `Me`).
+pub fn is_combining_mark(point: char) -> bool {
+    matches!(
+        point.general_category(),
+        GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+            | GeneralCategory::EnclosingMark,
+    )
+}
+
+// Returns `true` if the code point is a combining mark that is not a variation selector. Variation
+// selectors are nonspacing marks, but select a glyph rather than stack upon it.
+pub fn is_stacking_mark(point: char) -> bool {
+    is_combining_mark(point) && !is_variation_selector(point)
+}
+
+// Returns `true` if the code point is a mandatory line break (class `BK`, `CR`, `LF`, or `NL`) per
+// UAX14. This includes line and paragraph separators.
+pub fn is_line_break(point: char) -> bool {
+    matches!(
+        point,
+        '\n' | '\u{B}' | '\u{C}' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+// Returns `true` if the code point is a variation selector, including Mongolian free variation
+// selectors. Variation selectors select a glyph for the preceding code point.
+pub fn is_variation_selector(point: char) -> bool {
+    matches!(
+        point,
+        '\u{180B}'..='\u{180D}' | '\u{180F}' | '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}'
+    )
+}
+
+// Returns `true` if the code point is a conjoining Hangul filler, which stands in for a missing
+// leading consonant or vowel in a Hangul syllable.
+#[cfg(feature = "alloc")]
+pub fn is_conjoining_hangul_filler(point: char) -> bool {
+    matches!(point, '\u{115F}' | '\u{1160}')
+}
+
+// Returns `true` if the code point is a zero width joiner or tag character, which join or modify
+// emoji in emoji sequences.
+#[cfg(feature = "alloc")]
+pub fn is_emoji_joiner(point: char) -> bool {
+    matches!(point, '\u{200D}' | '\u{E0020}'..='\u{E007F}')
+}
+
+// Returns `true` if the code point is an emoji that is not ASCII. ASCII digits and symbols like
+// `#` have the `Emoji` property, but only begin emoji sequences as keycaps.
+pub fn is_emoji(point: char) -> bool {
+    !point.is_ascii() && point.is_emoji_char()
+}
+
+// Returns `true` if the code point is a zero-width code point that is commonly used to hide data in
+// text. See `ZERO_WIDTH`.
+pub fn is_zero_width(point: char) -> bool {
+    ZERO_WIDTH.binary_search(&point).is_ok()
+}
@@ -0,0 +1,126 @@
+//! Confusable detection and restriction levels per UTS39.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use unicode_script::Script;
+use unicode_security::mixed_script::AugmentedScriptSet;
+use unicode_security::GeneralSecurityProfile as _;
+
+#[cfg(feature = "alloc")]
+use crate::property;
+use crate::StrExt as _;
+
+/// The restriction level of text per UTS39.
+///
+/// Restriction levels describe how scripts are mixed in text, which is a common vector for
+/// spoofing: for example, `"p\u{430}ypal"` mixes Latin and Cyrillic and is confusable with
+/// `"paypal"`. Levels are ordered from most to least restrictive, so text satisfies a level if its
+/// restriction level is less than or equal to that level.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RestrictionLevel {
+    /// All characters are ASCII.
+    AsciiOnly,
+    /// All characters are from a single script, such as `"Ελληνικά"`.
+    SingleScript,
+    /// All characters are from a single script or from Latin and one of the combinations Han
+    /// with Bopomofo, Han with Hiragana and Katakana, or Han with Hangul, such as `"Tokyo東京"`.
+    HighlyRestrictive,
+    /// All characters are from Latin and one other recommended script except Cyrillic and Greek,
+    /// such as `"Հայաստան Armenia"`.
+    ModeratelyRestrictive,
+    /// Characters are from arbitrary mixtures of scripts, such as `"p\u{430}ypal"`.
+    MinimallyRestrictive,
+    /// Characters include some that are not allowed in identifiers per the General Security
+    /// Profile of UTS39.
+    Unrestricted,
+}
+
+// Detects the restriction level of text per UTS39. Unlike identifiers, text may be separated by
+// whitespace, so whitespace grapheme clusters are ignored.
+pub(crate) fn restriction_level(text: &str) -> RestrictionLevel {
+    let mut is_ascii = true;
+    let mut scripts = AugmentedScriptSet::default();
+    let mut non_latin_scripts = AugmentedScriptSet::default();
+    for point in text
+        .graphemes()
+        .filter(|grapheme| !grapheme.is_whitespace())
+        .flat_map(|grapheme| grapheme.as_str().chars())
+    {
+        if !point.identifier_allowed() {
+            return RestrictionLevel::Unrestricted;
+        }
+        is_ascii = is_ascii && point.is_ascii();
+        let point_scripts = AugmentedScriptSet::for_char(point);
+        scripts.intersect_with(point_scripts);
+        if !point_scripts.base.contains_script(Script::Latin) {
+            non_latin_scripts.intersect_with(point_scripts);
+        }
+    }
+    if is_ascii {
+        RestrictionLevel::AsciiOnly
+    }
+    else if !scripts.is_empty() {
+        RestrictionLevel::SingleScript
+    }
+    else if non_latin_scripts.hanb || non_latin_scripts.jpan || non_latin_scripts.kore {
+        RestrictionLevel::HighlyRestrictive
+    }
+    else if non_latin_scripts.base.len() == 1
+        && non_latin_scripts.base.iter().all(|script| {
+            script.is_recommended() && !matches!(script, Script::Cyrillic | Script::Greek)
+        })
+    {
+        RestrictionLevel::ModeratelyRestrictive
+    }
+    else {
+        RestrictionLevel::MinimallyRestrictive
+    }
+}
+
+// Computes the confusable skeleton of text per UTS39: default-ignorable code points are removed and
+// each remaining code point is replaced by its prototype in NFD.
+#[cfg(feature = "alloc")]
+pub(crate) fn skeleton(text: &str) -> String {
+    let visible: String = text
+        .chars()
+        .filter(|&point| !property::is_default_ignorable(point))
+        .collect();
+    unicode_security::skeleton(&visible).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+
+    use crate::security::RestrictionLevel;
+    use crate::StrExt as _;
+
+    #[rstest]
+    #[case::ascii("legible", RestrictionLevel::AsciiOnly)]
+    #[case::ascii_words("legible text", RestrictionLevel::AsciiOnly)]
+    #[case::greek("Ελληνικά", RestrictionLevel::SingleScript)]
+    #[case::japanese("練習しなくては", RestrictionLevel::SingleScript)]
+    #[case::latin_and_han("Tokyo東京", RestrictionLevel::HighlyRestrictive)]
+    #[case::latin_and_cyrillic("p\u{430}ypal", RestrictionLevel::MinimallyRestrictive)]
+    #[case::latin_and_greek("Αθήνα Athens", RestrictionLevel::MinimallyRestrictive)]
+    #[case::latin_and_armenian("Հայաստան Armenia", RestrictionLevel::ModeratelyRestrictive)]
+    #[case::zero_width_space("pay\u{200B}pal", RestrictionLevel::Unrestricted)]
+    fn restriction_level_of_str_then_level_eq(
+        #[case] text: &str,
+        #[case] expected: RestrictionLevel,
+    ) {
+        assert_eq!(text.restriction_level(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::cyrillic("p\u{430}ypal", "paypal")]
+    #[case::digit("paypa1", "paypal")]
+    #[case::zero_width_space("pay\u{200B}pal", "paypal")]
+    #[case::composition("caf\u{E9}", "cafe\u{301}")]
+    fn skeleton_of_str_then_skeleton_of_other_eq(#[case] text: &str, #[case] other: &str) {
+        assert_eq!(text.to_skeleton(), other.to_skeleton());
+    }
+}
@@ -0,0 +1,68 @@
+//! Display width of text per UCS and UAX11.
+
+use unicode_width::UnicodeWidthStr;
+
+/// The context in which the display width of text is measured.
+///
+/// Per UAX11, the display width of East Asian Ambiguous characters (such as `¡`, `±`, and `①`)
+/// depends on context: these characters are narrow in most contexts but wide in CJK contexts.
+/// Terminals and fonts used with Chinese, Japanese, and Korean text typically render them with
+/// two columns.
+///
+/// The context does not affect legibility: a character has no display width in one context if
+/// and only if it has no display width in the other.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum WidthContext {
+    /// East Asian Ambiguous characters are narrow.
+    #[default]
+    Narrow,
+    /// East Asian Ambiguous characters are wide.
+    Cjk,
+}
+
+impl WidthContext {
+    /// Gets the display width of the given string slice in columns in this context.
+    pub fn width(self, text: &str) -> usize {
+        match self {
+            WidthContext::Narrow => text.width(),
+            WidthContext::Cjk => text.width_cjk(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+
+    use crate::width::WidthContext;
+
+    #[rstest]
+    #[case::latin("legible", 7, 7)]
+    #[case::japanese("練習", 4, 4)]
+    #[case::ambiguous("±①", 2, 4)]
+    #[case::zero_width("\u{200B}", 0, 0)]
+    fn measure_str_in_context_then_width_eq(
+        #[case] text: &str,
+        #[case] narrow: usize,
+        #[case] cjk: usize,
+    ) {
+        assert_eq!(WidthContext::Narrow.width(text), narrow);
+        assert_eq!(WidthContext::Cjk.width(text), cjk);
+    }
+
+    #[rstest]
+    fn measure_char_in_contexts_then_zero_width_eq() {
+        let mut buffer = [0u8; 4];
+        for point in (0..=(char::MAX as u32)).filter_map(char::from_u32) {
+            let text = point.encode_utf8(&mut buffer);
+            assert_eq!(
+                WidthContext::Narrow.width(text) == 0,
+                WidthContext::Cjk.width(text) == 0,
+                "{:?}",
+                point,
+            );
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use lesbar::text::TextBuf;
+
+fn text(len: usize) -> TextBuf {
+    TextBuf::try_from("\u{200B}a\u{301}".repeat(len)).unwrap()
+}
+
+fn text_with_illegible_prefix(len: usize) -> TextBuf {
+    TextBuf::try_from("\u{200B}".repeat(len) + &"a".repeat(len)).unwrap()
+}
+
+fn text_with_legible_middle(len: usize) -> TextBuf {
+    TextBuf::try_from("\u{200B}".repeat(len) + "a" + &"\u{200B}".repeat(len)).unwrap()
+}
+
+fn pop(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("pop");
+    for len in [16, 256, 4096] {
+        group.bench_with_input(BenchmarkId::new("pop_char", len), &len, |bencher, &len| {
+            bencher.iter_batched_ref(
+                || text(len),
+                |text| while text.pop_char().or_false() {},
+                BatchSize::SmallInput,
+            )
+        });
+        group.bench_with_input(
+            BenchmarkId::new("pop_grapheme", len),
+            &len,
+            |bencher, &len| {
+                bencher.iter_batched_ref(
+                    || text(len),
+                    |text| while text.pop_grapheme().or_false() {},
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("pop_grapheme_with_illegible_prefix", len),
+            &len,
+            |bencher, &len| {
+                bencher.iter_batched_ref(
+                    || text_with_illegible_prefix(len),
+                    |text| while text.pop_grapheme().or_false() {},
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("pop_grapheme_with_legible_middle", len),
+            &len,
+            |bencher, &len| {
+                bencher.iter_batched_ref(
+                    || text_with_legible_middle(len),
+                    |text| while text.pop_grapheme().or_false() {},
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, pop);
+criterion_main!(benches);
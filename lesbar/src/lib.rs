@@ -10,19 +10,25 @@
 //!
 //! Lesbar supports `no_std` environments and provides features for integrating as needed with
 //! [`alloc`]. By default, the `alloc` feature is enabled for complete support of the standard
-//! library.
+//! library. Note that the [`caseless`] crate requires `std`, so the `caseless` feature cannot be
+//! used in `no_std` environments.
 //!
 //! The following table summarizes supported Cargo features and integrations.
 //!
 //! | Feature     | Default | Primary Dependency | Description                                         |
 //! |-------------|---------|--------------------|-----------------------------------------------------|
 //! | `alloc`     | Yes     | [`alloc`]          | Legible string buffer types like [`TextBuf`].       |
+//! | `caseless`  | No      | [`caseless`]       | Caseless comparison and hashing of legible strings. |
+//! | `precis`    | No      | [`unicode-bidi`]   | PRECIS profiles like [`NicknameTextBuf`].           |
 //! | `serde`     | No      | [`serde`]          | De/serialization of legible strings with [`serde`]. |
 //!
+//! [`caseless`]: https://crates.io/crates/caseless
+//! [`NicknameTextBuf`]: crate::text::NicknameTextBuf
 //! [`serde`]: https://crates.io/crates/serde
 //! [`Str1`]: mitsein::str1::Str1
 //! [`Text`]: crate::text::Text
 //! [`TextBuf`]: crate::text::TextBuf
+//! [`unicode-bidi`]: https://crates.io/crates/unicode-bidi
 
 // SAFETY: This crate is somewhat more conservative than the `mitsein` crate regarding unsafe code.
 //         While it uses unsafe code, this is only done when strictly necessary (mostly for
@@ -53,12 +59,12 @@ pub mod text;
 pub mod prelude {
     //! Re-exports of recommended APIs and extension traits.
 
-    pub use crate::text::Text;
+    pub use crate::text::{Line, Text, VisibleText};
     pub use crate::StrExt as _;
     #[cfg(feature = "alloc")]
     pub use {
         crate::grapheme::CowGraphemeExt as _,
-        crate::text::{CowTextExt as _, TextBuf},
+        crate::text::{CowTextExt as _, LineBuf, TextBuf, VisibleTextBuf},
     };
 }
 
@@ -66,15 +72,25 @@ pub mod prelude {
 use ::serde::{Deserialize, Serialize};
 #[cfg(feature = "alloc")]
 use alloc::borrow::ToOwned;
+use core::cmp::Ordering;
 use core::error::Error;
 use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Range;
 use mitsein::NonEmpty;
 
 #[cfg(feature = "serde")]
 use crate::serde::Serde;
 
-pub use lesbar_macros::{str1, text};
-pub use lesbar_text::{grapheme, iter, RuneError, StrExt};
+pub use lesbar_macros::{line, str1, text, visible};
+#[cfg(feature = "precis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "precis")))]
+pub use lesbar_text::precis;
+pub use lesbar_text::{diagnosis, grapheme, iter, policy, security, width, RuneError, StrExt};
+
+use crate::diagnosis::{Diagnosis, Illegibility};
+use crate::policy::{LegibilityPolicy, NonBlank, Standard};
 
 const ILLEGIBLE_ERROR_MESSAGE: &str = "failed to construct text: no legible content";
 
@@ -82,11 +98,19 @@ const ILLEGIBLE_ERROR_MESSAGE: &str = "failed to construct text: no legible cont
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
 pub struct IllegibleError<T> {
     illegible: T,
+    diagnosis: Diagnosis,
 }
 
 impl<T> IllegibleError<T> {
-    fn from_illegible(illegible: T) -> Self {
-        IllegibleError { illegible }
+    fn from_illegible(illegible: T, diagnosis: Diagnosis) -> Self {
+        IllegibleError {
+            illegible,
+            diagnosis,
+        }
+    }
+
+    fn from_empty(illegible: T) -> Self {
+        IllegibleError::from_illegible(illegible, Diagnosis::empty())
     }
 
     pub fn into_illegible(self) -> T {
@@ -99,11 +123,15 @@ impl<T> IllegibleError<T> {
     {
         IllegibleError {
             illegible: f(self.illegible),
+            diagnosis: self.diagnosis,
         }
     }
 
     pub fn take(self) -> (T, IllegibleError<()>) {
-        (self.illegible, IllegibleError::from_illegible(()))
+        (
+            self.illegible,
+            IllegibleError::from_illegible((), self.diagnosis),
+        )
     }
 
     pub fn take_and_drop(self) -> IllegibleError<()> {
@@ -113,6 +141,21 @@ impl<T> IllegibleError<T> {
     pub fn as_illegible(&self) -> &T {
         &self.illegible
     }
+
+    pub fn diagnosis(&self) -> Diagnosis {
+        self.diagnosis
+    }
+
+    pub fn reason(&self) -> Illegibility {
+        self.diagnosis.illegibility()
+    }
+
+    /// Gets the byte span of the first offending grapheme cluster, if any.
+    ///
+    /// This span is `None` if the input is empty.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.diagnosis.span()
+    }
 }
 
 impl<T> IllegibleError<&'_ T> {
@@ -122,7 +165,7 @@ impl<T> IllegibleError<&'_ T> {
     where
         T: ToOwned,
     {
-        IllegibleError::from_illegible(self.illegible.to_owned())
+        IllegibleError::from_illegible(self.illegible.to_owned(), self.diagnosis)
     }
 }
 
@@ -130,18 +173,27 @@ impl<T> Debug for IllegibleError<T> {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         formatter
             .debug_struct("IllegibleError")
+            .field("diagnosis", &self.diagnosis)
             .finish_non_exhaustive()
     }
 }
 
 impl<T> Display for IllegibleError<T> {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
-        write!(formatter, "{ILLEGIBLE_ERROR_MESSAGE}")
+        write!(formatter, "{ILLEGIBLE_ERROR_MESSAGE}: {}", self.diagnosis)
     }
 }
 
 impl<T> Error for IllegibleError<T> {}
 
+// Diagnoses text that is known to be illegible per the policy `P`.
+fn diagnose<P>(text: &str) -> Diagnosis
+where
+    P: LegibilityPolicy,
+{
+    P::diagnose(text).unwrap_or_else(|| Diagnosis::from_illegible_str(text))
+}
+
 #[cfg_attr(
     feature = "serde",
     derive(::serde_derive::Deserialize, ::serde_derive::Serialize)
@@ -158,22 +210,128 @@ impl<T> Error for IllegibleError<T> {}
         into = "Serde<NonEmpty<T>>",
     )
 )]
-// Though this type contains a `NonEmpty<T>` rather than a `T`, this `derive` is correct, since
-// `NonEmpty` also implements these traits with bounds on `T`.
-#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
-pub struct Legible<T>
+pub struct Legible<T, P = Standard>
 where
     T: ?Sized,
 {
+    policy: PhantomData<fn() -> P>,
     text: NonEmpty<T>,
 }
 
-impl<T> AsRef<T> for Legible<T> {
+/// Legible text that is not blank.
+///
+/// Visible text has at least one legible grapheme cluster that is not whitespace. See
+/// [`NonBlank`].
+pub type Visible<T> = Legible<T, NonBlank>;
+
+impl<T, P> Legible<T, P> {
+    const fn from_non_empty_unchecked(text: NonEmpty<T>) -> Self {
+        Legible {
+            policy: PhantomData,
+            text,
+        }
+    }
+}
+
+impl<T, P> AsRef<T> for Legible<T, P> {
     fn as_ref(&self) -> &T {
         self.text.as_ref()
     }
 }
 
+// The following implementations are written by hand rather than derived, because derived
+// implementations require that the policy type parameter `P` also implements these traits.
+// Policies are only markers and are never constructed by `Legible`.
+
+impl<T, P> Clone for Legible<T, P>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Legible::from_non_empty_unchecked(self.text.clone())
+    }
+}
+
+impl<T, P> Copy for Legible<T, P> where T: Copy {}
+
+impl<T, P> Eq for Legible<T, P> where T: Eq + ?Sized {}
+
+impl<T, P> Hash for Legible<T, P>
+where
+    T: Hash + ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.text.hash(state)
+    }
+}
+
+impl<T, P> Ord for Legible<T, P>
+where
+    T: Ord + ?Sized,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.text.cmp(&other.text)
+    }
+}
+
+impl<T, P> PartialEq for Legible<T, P>
+where
+    T: PartialEq + ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.text.eq(&other.text)
+    }
+}
+
+impl<T, P> PartialOrd for Legible<T, P>
+where
+    T: PartialOrd + ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.text.partial_cmp(&other.text)
+    }
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    extern crate std;
+
+    use core::ops::Range;
+    use rstest::rstest;
+    use std::string::ToString as _;
+
+    use crate::diagnosis::Illegibility;
+    use crate::policy::Strict;
+    use crate::text::Text;
+    use crate::Legible;
+
+    #[rstest]
+    #[case::empty("", Illegibility::Empty, None)]
+    #[case::zwsp("\u{200B}", Illegibility::DefaultIgnorable, Some(0..3))]
+    #[case::zwsp_and_lrm("\u{200B}\u{200E}", Illegibility::DefaultIgnorable, Some(0..3))]
+    #[case::acute("\u{301}", Illegibility::CombiningMark, Some(0..2))]
+    #[case::private_use("\u{E000}", Illegibility::PrivateUse, Some(0..3))]
+    fn try_from_illegible_str_with_strict_policy_then_illegible_error_reason_and_span_eq(
+        #[case] text: &str,
+        #[case] reason: Illegibility,
+        #[case] span: Option<Range<usize>>,
+    ) {
+        let error = Legible::<str, Strict>::try_from_str(text).unwrap_err();
+        assert_eq!(error.reason(), reason);
+        assert_eq!(error.span(), span);
+    }
+
+    #[rstest]
+    fn display_illegible_error_then_message_has_reason_and_span() {
+        let error = Text::try_from_str("\u{200B}").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "failed to construct text: no legible content: only default-ignorable code points at \
+             bytes 0..3",
+        );
+    }
+}
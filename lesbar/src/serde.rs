@@ -12,20 +12,20 @@ pub struct Serde<T> {
     text: T,
 }
 
-impl<T> From<Legible<T>> for Serde<NonEmpty<T>> {
-    fn from(text: Legible<T>) -> Self {
+impl<T, P> From<Legible<T, P>> for Serde<NonEmpty<T>> {
+    fn from(text: Legible<T, P>) -> Self {
         Serde { text: text.text }
     }
 }
 
-impl<T, U> TryFrom<Serde<U>> for Legible<T>
+impl<T, U, P> TryFrom<Serde<U>> for Legible<T, P>
 where
-    Legible<T>: TryFrom<U, Error = IllegibleError<U>>,
+    Legible<T, P>: TryFrom<U, Error = IllegibleError<U>>,
 {
     type Error = IllegibleError<U>;
 
     fn try_from(text: Serde<U>) -> Result<Self, Self::Error> {
-        <Legible<T> as TryFrom<U>>::try_from(text.text)
+        <Legible<T, P> as TryFrom<U>>::try_from(text.text)
     }
 }
 
@@ -38,8 +38,6 @@ pub mod harness {
     use serde::{Deserialize, Serialize};
     use serde_test::{self, Token};
 
-    use crate::ILLEGIBLE_ERROR_MESSAGE;
-
     #[fixture]
     pub fn legible() -> impl Iterator<Item = Token> {
         self::borrowed_str_token("legible")
@@ -61,13 +59,14 @@ pub mod harness {
         serde_test::assert_tokens(&text, tokens.as_ref());
     }
 
-    pub fn assert_deserialize_error_eq_illegible_error<T, N>(
+    pub fn assert_deserialize_error_eq<T, N>(
         tokens: impl IntoIterator<Item = Token>,
+        expected: &str,
     ) where
         for<'de> T: Debug + Deserialize<'de> + PartialEq + Serialize,
         N: AsRef<[Token]> + FromIterator<Token>,
     {
         let tokens: N = tokens.into_iter().collect();
-        serde_test::assert_de_tokens_error::<T>(tokens.as_ref(), ILLEGIBLE_ERROR_MESSAGE);
+        serde_test::assert_de_tokens_error::<T>(tokens.as_ref(), expected);
     }
 }
@@ -0,0 +1,253 @@
+//! Width-aware padding and alignment of legible text.
+
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "alloc")]
+use {alloc::borrow::Cow, alloc::string::String};
+
+use crate::policy::LegibilityPolicy;
+use crate::text::Columns;
+#[cfg(feature = "alloc")]
+use crate::IllegibleError;
+use crate::Legible;
+
+/// The alignment of text within padding.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Align {
+    /// Padding is inserted after the text.
+    #[default]
+    Left,
+    /// Padding is inserted before and after the text.
+    ///
+    /// If the padding cannot be split evenly, then the extra column is inserted after the text.
+    Center,
+    /// Padding is inserted before the text.
+    Right,
+}
+
+impl Align {
+    // Splits the given padding into leading and trailing padding.
+    fn split(self, padding: usize) -> (usize, usize) {
+        match self {
+            Align::Left => (0, padding),
+            Align::Center => (padding / 2, padding - (padding / 2)),
+            Align::Right => (padding, 0),
+        }
+    }
+}
+
+/// A [`Display`] adapter that pads text with spaces to a display width.
+///
+/// Unlike the width and alignment of format specifiers, which count `char`s, padding is computed
+/// from the display width of the text in columns. Display width is measured in the context of the
+/// given [`Columns`].
+///
+/// See [`Legible::aligned`].
+pub struct Aligned<'t, P> {
+    text: &'t Legible<str, P>,
+    align: Align,
+    width: Columns,
+}
+
+impl<'t, P> Aligned<'t, P>
+where
+    P: LegibilityPolicy,
+{
+    fn padding(&self) -> (usize, usize) {
+        self.align.split(
+            self.width
+                .width()
+                .saturating_sub(self.width.measure(self.text.as_str())),
+        )
+    }
+}
+
+impl<P> Clone for Aligned<'_, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for Aligned<'_, P> {}
+
+impl<P> Debug for Aligned<'_, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Aligned")
+            .field("text", &self.text.as_str())
+            .field("align", &self.align)
+            .field("width", &self.width)
+            .finish()
+    }
+}
+
+impl<P> Display for Aligned<'_, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        let (leading, trailing) = self.padding();
+        write!(
+            formatter,
+            "{:leading$}{}{:trailing$}",
+            "",
+            self.text.as_str(),
+            "",
+        )
+    }
+}
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Gets a [`Display`] adapter that pads the text with spaces to the given display width in
+    /// columns.
+    ///
+    /// Text that is at least as wide as `width` is displayed without padding.
+    pub fn aligned(&self, align: Align, width: impl Into<Columns>) -> Aligned<'_, P> {
+        Aligned {
+            text: self,
+            align,
+            width: width.into(),
+        }
+    }
+
+    /// Pads the text with spaces to the given display width in columns.
+    ///
+    /// The text is borrowed if it is at least as wide as `width`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the padded text is illegible per the policy `P`, such as when spaces are
+    /// not admissible.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn pad_to_width(
+        &self,
+        align: Align,
+        width: impl Into<Columns>,
+    ) -> Result<Cow<'_, Legible<str, P>>, IllegibleError<String>> {
+        let aligned = self.aligned(align, width);
+        match aligned.padding() {
+            (0, 0) => Ok(Cow::Borrowed(self)),
+            (leading, trailing) => {
+                let mut text = String::with_capacity(leading + self.len().get() + trailing);
+                text.extend(core::iter::repeat_n(' ', leading));
+                text.push_str(self.as_str());
+                text.extend(core::iter::repeat_n(' ', trailing));
+                Legible::try_from(text).map(Cow::Owned)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    #[cfg(feature = "alloc")]
+    use alloc::borrow::Cow;
+    use rstest::rstest;
+    use std::format;
+
+    use crate::text::{Align, Columns, Text};
+    #[cfg(feature = "alloc")]
+    use {crate::grapheme::Grapheme, crate::policy::LegibilityPolicy, crate::Legible};
+
+    #[cfg(feature = "alloc")]
+    struct NoSpace;
+
+    #[cfg(feature = "alloc")]
+    impl LegibilityPolicy for NoSpace {
+        fn is_legible_grapheme(grapheme: &Grapheme) -> bool {
+            grapheme.is_legible_text()
+        }
+
+        fn is_admissible_grapheme(grapheme: &Grapheme) -> bool {
+            grapheme.as_str() != " "
+        }
+    }
+
+    #[rstest]
+    #[case::left("text", Align::Left, 8, "text    ")]
+    #[case::center("text", Align::Center, 8, "  text  ")]
+    #[case::center_uneven("text", Align::Center, 7, " text  ")]
+    #[case::right("text", Align::Right, 8, "    text")]
+    #[case::wide_left("練習", Align::Left, 6, "練習  ")]
+    #[case::wide_center("練習", Align::Center, 6, " 練習 ")]
+    #[case::wide_right("練習", Align::Right, 6, "  練習")]
+    #[case::emoji(
+        "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+        Align::Right,
+        4,
+        "  \u{1F3F3}\u{FE0F}\u{200D}\u{1F308}"
+    )]
+    #[case::narrow("legible", Align::Center, 4, "legible")]
+    fn align_text_then_display_eq(
+        #[case] text: &str,
+        #[case] align: Align,
+        #[case] width: usize,
+        #[case] expected: &str,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(format!("{}", text.aligned(align, width)), expected);
+    }
+
+    #[rstest]
+    #[case::left("±①", Align::Left, 6, "±①  ")]
+    #[case::center("±①", Align::Center, 6, " ±① ")]
+    #[case::right("±①", Align::Right, 6, "  ±①")]
+    fn align_text_to_cjk_width_then_display_eq(
+        #[case] text: &str,
+        #[case] align: Align,
+        #[case] width: usize,
+        #[case] expected: &str,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(
+            format!("{}", text.aligned(align, Columns::cjk(width))),
+            expected,
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::left("text", Align::Left, Columns::from(8), "text    ")]
+    #[case::center("text", Align::Center, Columns::from(7), " text  ")]
+    #[case::right("練習", Align::Right, Columns::from(6), "  練習")]
+    #[case::cjk("±①", Align::Center, Columns::cjk(6), " ±① ")]
+    fn pad_text_then_text_eq(
+        #[case] text: &str,
+        #[case] align: Align,
+        #[case] width: Columns,
+        #[case] expected: &str,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        let padded = text.pad_to_width(align, width).unwrap();
+        assert!(matches!(padded, Cow::Owned(_)));
+        assert_eq!(padded.as_ref(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::exact("text", 4)]
+    #[case::narrow("text", 2)]
+    fn pad_text_to_narrower_width_then_borrowed(#[case] text: &str, #[case] width: usize) {
+        let text = Text::try_from_str(text).unwrap();
+        assert!(matches!(
+            text.pad_to_width(Align::Center, width),
+            Ok(Cow::Borrowed(_)),
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    fn pad_text_with_inadmissible_spaces_then_illegible_error() {
+        let text = Legible::<str, NoSpace>::try_from_str("text").unwrap();
+        let error = text.pad_to_width(Align::Center, 8).unwrap_err();
+        assert_eq!(error.into_illegible(), "  text  ");
+    }
+}
@@ -0,0 +1,451 @@
+//! Balancing and isolation of bidirectional formatting characters in legible text.
+
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter, Write};
+#[cfg(feature = "alloc")]
+use {alloc::borrow::Cow, alloc::string::String};
+
+use crate::policy::LegibilityPolicy;
+#[cfg(feature = "alloc")]
+use crate::IllegibleError;
+use crate::Legible;
+
+// The maximum depth of embeddings, overrides, and isolates per UAX9.
+const MAX_DEPTH: usize = 125;
+
+const FSI: char = '\u{2068}';
+const PDF: char = '\u{202C}';
+const PDI: char = '\u{2069}';
+
+/// Describes how bidirectional formatting characters in text are unbalanced.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Imbalance {
+    /// A terminator (PDF or PDI) does not close an initiator.
+    Unmatched,
+    /// An initiator (LRE, RLE, LRO, RLO, LRI, RLI, or FSI) is not closed by its terminator before
+    /// the end of its paragraph or its enclosing isolate.
+    Unterminated,
+    /// An initiator exceeds the maximum depth of 125 per UAX9.
+    Overflow,
+}
+
+impl Display for Imbalance {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Imbalance::Unmatched => "unmatched terminator",
+            Imbalance::Unterminated => "unterminated initiator",
+            Imbalance::Overflow => "initiator exceeds maximum depth",
+        })
+    }
+}
+
+/// An error that describes unbalanced bidirectional formatting characters in text.
+///
+/// See [`Legible::check_bidi`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BidiError {
+    imbalance: Imbalance,
+    index: usize,
+}
+
+impl BidiError {
+    pub fn imbalance(&self) -> Imbalance {
+        self.imbalance
+    }
+
+    /// Gets the byte index of the unbalanced bidirectional formatting character in the text.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl Display for BidiError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "unbalanced bidirectional formatting: {} at index {}",
+            self.imbalance, self.index,
+        )
+    }
+}
+
+impl Error for BidiError {}
+
+/// A [`Display`] adapter that isolates text with bidirectional formatting characters.
+///
+/// See [`Legible::isolate`].
+pub struct Isolated<'t, P> {
+    text: &'t Legible<str, P>,
+}
+
+impl<P> Clone for Isolated<'_, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for Isolated<'_, P> {}
+
+impl<P> Debug for Isolated<'_, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Isolated")
+            .field("text", &self.text.as_str())
+            .finish()
+    }
+}
+
+impl<P> Display for Isolated<'_, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_char(FSI)?;
+        scan(self.text.as_str(), |event| match event {
+            Event::Separator(separator) => {
+                formatter.write_char(PDI)?;
+                formatter.write_str(separator)?;
+                formatter.write_char(FSI)
+            },
+            event => write_balanced(formatter, event),
+        })?;
+        formatter.write_char(PDI)
+    }
+}
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Checks that the bidirectional formatting characters in the text are balanced.
+    ///
+    /// Text is balanced if each embedding and override initiator (LRE, RLE, LRO, and RLO) is
+    /// closed by a PDF and each isolate initiator (LRI, RLI, and FSI) is closed by a PDI within
+    /// the same paragraph and enclosing isolate, and each PDF and PDI closes such an initiator.
+    /// Unbalanced formatting characters can reorder the text that surrounds interpolated text, as
+    /// in the Trojan Source attack. Text is balanced if it has no bidirectional formatting
+    /// characters.
+    ///
+    /// See [`close_bidi`] to balance text instead.
+    ///
+    /// [`close_bidi`]: crate::Legible::close_bidi
+    pub fn check_bidi(&self) -> Result<(), BidiError> {
+        scan(self.as_str(), |event| match event {
+            Event::Text(_) | Event::Separator(_) => Ok(()),
+            Event::Unmatched(index) => Err(BidiError {
+                imbalance: Imbalance::Unmatched,
+                index,
+            }),
+            Event::Unterminated(index, _) => Err(BidiError {
+                imbalance: Imbalance::Unterminated,
+                index,
+            }),
+            Event::Overflow(index) => Err(BidiError {
+                imbalance: Imbalance::Overflow,
+                index,
+            }),
+        })
+    }
+
+    /// Balances the bidirectional formatting characters in the text.
+    ///
+    /// Terminators are inserted to close unterminated initiators and unmatched terminators and
+    /// initiators that exceed the maximum depth are removed. The text is borrowed if it is
+    /// balanced (see [`check_bidi`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the balanced text is not legible per the policy `P`.
+    ///
+    /// [`check_bidi`]: crate::Legible::check_bidi
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn close_bidi(&self) -> Result<Cow<'_, Legible<str, P>>, IllegibleError<String>> {
+        if self.check_bidi().is_ok() {
+            return Ok(Cow::Borrowed(self));
+        }
+        let mut text = String::with_capacity(self.len().get());
+        scan(self.as_str(), |event| write_balanced(&mut text, event))
+            .expect("failed to write balanced text");
+        Legible::try_from(text).map(Cow::Owned)
+    }
+
+    /// Gets a [`Display`] adapter that isolates the text with bidirectional formatting
+    /// characters.
+    ///
+    /// The text is balanced (see [`close_bidi`]) and each of its paragraphs is enclosed in an
+    /// isolate (FSI and PDI), so that the text does not affect the order of surrounding text when
+    /// it is interpolated. The direction of each paragraph is determined by its first strong
+    /// character.
+    ///
+    /// [`close_bidi`]: crate::Legible::close_bidi
+    pub fn isolate(&self) -> Isolated<'_, P> {
+        Isolated { text: self }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Initiator {
+    Embedding,
+    Isolate,
+}
+
+impl Initiator {
+    fn terminator(self) -> char {
+        match self {
+            Initiator::Embedding => PDF,
+            Initiator::Isolate => PDI,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Event<'t> {
+    // A run of text that is written as is.
+    Text(&'t str),
+    // A paragraph separator.
+    Separator(&'t str),
+    // An unmatched terminator at the given index that is removed.
+    Unmatched(usize),
+    // An initiator at the given index that is closed by inserting its terminator.
+    Unterminated(usize, Initiator),
+    // An initiator at the given index that exceeds the maximum depth and is removed.
+    Overflow(usize),
+}
+
+// A stack of open initiators and their indices.
+struct Stack {
+    initiators: [(usize, Initiator); MAX_DEPTH],
+    depth: usize,
+    // The number of initiators that exceed the maximum depth and have not been closed.
+    overflow: usize,
+}
+
+impl Stack {
+    fn new() -> Self {
+        Stack {
+            initiators: [(0, Initiator::Embedding); MAX_DEPTH],
+            depth: 0,
+            overflow: 0,
+        }
+    }
+
+    fn push(&mut self, index: usize, initiator: Initiator) -> bool {
+        if self.depth < MAX_DEPTH && self.overflow == 0 {
+            self.initiators[self.depth] = (index, initiator);
+            self.depth += 1;
+            true
+        }
+        else {
+            self.overflow += 1;
+            false
+        }
+    }
+
+    fn pop(&mut self) -> Option<(usize, Initiator)> {
+        self.depth = self.depth.checked_sub(1)?;
+        Some(self.initiators[self.depth])
+    }
+
+    fn peek(&self) -> Option<Initiator> {
+        self.depth.checked_sub(1).map(|top| self.initiators[top].1)
+    }
+
+    fn has_isolate(&self) -> bool {
+        self.initiators[..self.depth]
+            .iter()
+            .any(|(_, initiator)| matches!(initiator, Initiator::Isolate))
+    }
+}
+
+// Scans text for unbalanced bidirectional formatting characters per UAX9 and visits the text as a
+// sequence of events from which balanced text can be written.
+fn scan<'t, E>(text: &'t str, mut visit: impl FnMut(Event<'t>) -> Result<(), E>) -> Result<(), E> {
+    let mut stack = Stack::new();
+    let mut start = 0;
+    let mut points = text.char_indices().peekable();
+    while let Some((index, point)) = points.next() {
+        match point {
+            '\u{202A}' | '\u{202B}' | '\u{202D}' | '\u{202E}' | '\u{2066}' | '\u{2067}' | FSI => {
+                let initiator = if matches!(point, '\u{2066}' | '\u{2067}' | FSI) {
+                    Initiator::Isolate
+                }
+                else {
+                    Initiator::Embedding
+                };
+                if !stack.push(index, initiator) {
+                    visit(Event::Text(&text[start..index]))?;
+                    visit(Event::Overflow(index))?;
+                    start = index + point.len_utf8();
+                }
+            },
+            PDF | PDI => {
+                if stack.overflow == 0 {
+                    if point == PDF && matches!(stack.peek(), Some(Initiator::Embedding)) {
+                        stack.pop();
+                        continue;
+                    }
+                    if point == PDI && stack.has_isolate() {
+                        visit(Event::Text(&text[start..index]))?;
+                        start = index;
+                        while let Some((index, initiator)) = stack.pop() {
+                            if let Initiator::Isolate = initiator {
+                                break;
+                            }
+                            visit(Event::Unterminated(index, initiator))?;
+                        }
+                        continue;
+                    }
+                }
+                visit(Event::Text(&text[start..index]))?;
+                if stack.overflow > 0 {
+                    // Initiators that exceed the maximum depth are removed, so their terminators
+                    // are also removed.
+                    stack.overflow -= 1;
+                }
+                else {
+                    visit(Event::Unmatched(index))?;
+                }
+                start = index + point.len_utf8();
+            },
+            '\n' | '\r' | '\u{1C}'..='\u{1E}' | '\u{85}' | '\u{2029}' => {
+                visit(Event::Text(&text[start..index]))?;
+                while let Some((index, initiator)) = stack.pop() {
+                    visit(Event::Unterminated(index, initiator))?;
+                }
+                stack.overflow = 0;
+                let end = if point == '\r' && points.next_if(|&(_, point)| point == '\n').is_some()
+                {
+                    index + 2
+                }
+                else {
+                    index + point.len_utf8()
+                };
+                visit(Event::Separator(&text[index..end]))?;
+                start = end;
+            },
+            _ => {},
+        }
+    }
+    visit(Event::Text(&text[start..]))?;
+    while let Some((index, initiator)) = stack.pop() {
+        visit(Event::Unterminated(index, initiator))?;
+    }
+    Ok(())
+}
+
+fn write_balanced<W>(output: &mut W, event: Event<'_>) -> fmt::Result
+where
+    W: Write,
+{
+    match event {
+        Event::Text(text) | Event::Separator(text) => output.write_str(text),
+        Event::Unterminated(_, initiator) => output.write_char(initiator.terminator()),
+        Event::Unmatched(_) | Event::Overflow(_) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    #[cfg(feature = "alloc")]
+    use alloc::borrow::Cow;
+    use rstest::rstest;
+    use std::format;
+    use std::string::String;
+
+    use crate::text::{Imbalance, Text};
+
+    #[rstest]
+    #[case::legible("legible")]
+    #[case::embedding("\u{202B}legible\u{202C}")]
+    #[case::override_in_isolate("\u{2067}\u{202E}legible\u{202C}\u{2069}")]
+    #[case::paragraphs("\u{202E}a\u{202C}\n\u{2068}b\u{2069}")]
+    fn check_balanced_bidi_in_text_then_ok(#[case] text: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        assert!(text.check_bidi().is_ok());
+    }
+
+    #[rstest]
+    #[case::unmatched_pdf("a\u{202C}", Imbalance::Unmatched, 1)]
+    #[case::unmatched_pdi("a\u{2069}", Imbalance::Unmatched, 1)]
+    #[case::pdf_closes_isolate("\u{2067}a\u{202C}\u{2069}", Imbalance::Unmatched, 4)]
+    #[case::unterminated_override("\u{202E}txt.exe", Imbalance::Unterminated, 0)]
+    #[case::unterminated_in_isolate("\u{2067}a\u{202A}b\u{2069}", Imbalance::Unterminated, 4)]
+    #[case::unterminated_in_paragraph("\u{202E}a\nb\u{202C}", Imbalance::Unterminated, 0)]
+    fn check_unbalanced_bidi_in_text_then_error_eq(
+        #[case] text: &str,
+        #[case] imbalance: Imbalance,
+        #[case] index: usize,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        let error = text.check_bidi().unwrap_err();
+        assert_eq!(error.imbalance(), imbalance);
+        assert_eq!(error.index(), index);
+    }
+
+    #[rstest]
+    fn check_deep_bidi_in_text_then_overflow_error() {
+        let text: String = core::iter::repeat_n('\u{202A}', 126)
+            .chain(core::iter::once('a'))
+            .chain(core::iter::repeat_n('\u{202C}', 126))
+            .collect();
+        let text = Text::try_from_str(&text).unwrap();
+        let error = text.check_bidi().unwrap_err();
+        assert_eq!(error.imbalance(), Imbalance::Overflow);
+        assert_eq!(error.index(), 125 * 3);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    fn close_deep_bidi_in_text_then_overflow_removed() {
+        let text: String = core::iter::repeat_n('\u{202A}', 126)
+            .chain(core::iter::once('a'))
+            .chain(core::iter::repeat_n('\u{202C}', 126))
+            .collect();
+        let text = Text::try_from_str(&text).unwrap();
+        let closed = text.close_bidi().unwrap();
+        assert_eq!(closed.len().get(), text.len().get() - 6);
+        assert!(closed.check_bidi().is_ok());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::unmatched("a\u{202C}b\u{2069}", "ab")]
+    #[case::unterminated_override("\u{202E}txt.exe", "\u{202E}txt.exe\u{202C}")]
+    #[case::unterminated_isolates("\u{2067}\u{2066}a", "\u{2067}\u{2066}a\u{2069}\u{2069}")]
+    #[case::unterminated_in_isolate(
+        "\u{2067}a\u{202A}b\u{2069}",
+        "\u{2067}a\u{202A}b\u{202C}\u{2069}"
+    )]
+    #[case::unterminated_in_paragraph("\u{202E}a\r\nb", "\u{202E}a\u{202C}\r\nb")]
+    fn close_bidi_in_text_then_balanced_eq(#[case] text: &str, #[case] expected: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        let closed = text.close_bidi().unwrap();
+        assert!(matches!(closed, Cow::Owned(_)));
+        assert_eq!(closed.as_ref(), expected);
+        assert!(closed.check_bidi().is_ok());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    fn close_balanced_bidi_in_text_then_borrowed() {
+        let text = Text::try_from_str("\u{2067}legible\u{2069}").unwrap();
+        assert!(matches!(text.close_bidi().unwrap(), Cow::Borrowed(_)));
+    }
+
+    #[rstest]
+    #[case::legible("legible", "\u{2068}legible\u{2069}")]
+    #[case::unterminated_override("\u{202E}evil", "\u{2068}\u{202E}evil\u{202C}\u{2069}")]
+    #[case::unmatched_pdi("evil\u{2069}", "\u{2068}evil\u{2069}")]
+    #[case::paragraphs("a\nb", "\u{2068}a\u{2069}\n\u{2068}b\u{2069}")]
+    #[case::crlf("a\r\nb", "\u{2068}a\u{2069}\r\n\u{2068}b\u{2069}")]
+    fn isolate_text_then_display_eq(#[case] text: &str, #[case] expected: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(format!("{}", text.isolate()), expected);
+    }
+}
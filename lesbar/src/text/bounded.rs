@@ -0,0 +1,499 @@
+//! Legible text with a bounded length.
+
+#![cfg(feature = "alloc")]
+#![cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+
+#[cfg(feature = "serde")]
+use ::serde::{Deserialize, Serialize};
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Deref, RangeInclusive};
+use unicode_width::UnicodeWidthStr;
+
+use crate::policy::{LegibilityPolicy, Standard};
+use crate::{IllegibleError, Legible, StrExt as _};
+
+/// A measure of the length of text.
+///
+/// Implementations must be deterministic: the measure of a given string slice must never change.
+pub trait Measure {
+    fn measure(text: &str) -> usize;
+}
+
+/// Measures the length of text as its number of grapheme clusters.
+#[derive(Clone, Copy, Debug)]
+pub struct GraphemeCount;
+
+impl Measure for GraphemeCount {
+    fn measure(text: &str) -> usize {
+        text.graphemes().count()
+    }
+}
+
+/// Measures the length of text as its display width in columns per UCS and UAX11.
+#[derive(Clone, Copy, Debug)]
+pub struct Width;
+
+impl Measure for Width {
+    fn measure(text: &str) -> usize {
+        text.width()
+    }
+}
+
+/// Measures the length of text as its display width in columns per UCS and UAX11 in a CJK
+/// context.
+///
+/// See [`WidthContext::Cjk`].
+///
+/// [`WidthContext::Cjk`]: crate::width::WidthContext::Cjk
+#[derive(Clone, Copy, Debug)]
+pub struct WidthCjk;
+
+impl Measure for WidthCjk {
+    fn measure(text: &str) -> usize {
+        text.width_cjk()
+    }
+}
+
+/// Legible text with between `MIN` and `MAX` (inclusive) grapheme clusters.
+pub type BoundedText<const MIN: usize, const MAX: usize> = Bounded<MIN, MAX>;
+
+/// Legible text with a display width of between `MIN` and `MAX` (inclusive) columns.
+pub type BoundedWidthText<const MIN: usize, const MAX: usize> = Bounded<MIN, MAX, Width>;
+
+/// Legible text with a display width of between `MIN` and `MAX` (inclusive) columns in a CJK
+/// context.
+pub type BoundedWidthCjkText<const MIN: usize, const MAX: usize> = Bounded<MIN, MAX, WidthCjk>;
+
+/// Legible text with a length of between `MIN` and `MAX` (inclusive) per the measure `M`.
+///
+/// The length of `Bounded` text is checked when it is constructed, including when it is
+/// deserialized. `MIN` must not exceed `MAX`.
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde_derive::Deserialize, ::serde_derive::Serialize)
+)]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        bound(
+            deserialize = "M: Measure, P: LegibilityPolicy, String: Deserialize<'de>,",
+            serialize = "M: Measure, P: LegibilityPolicy, String: Serialize,",
+        ),
+        try_from = "String",
+        into = "String",
+    )
+)]
+pub struct Bounded<const MIN: usize, const MAX: usize, M = GraphemeCount, P = Standard> {
+    measure: PhantomData<fn() -> M>,
+    text: Legible<String, P>,
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> Bounded<MIN, MAX, M, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    pub fn try_from_text_buf(
+        text: Legible<String, P>,
+    ) -> Result<Self, OutOfBoundsError<Legible<String, P>>> {
+        const {
+            assert!(
+                MIN <= MAX,
+                "minimum length bound exceeds maximum length bound"
+            )
+        };
+        let measure = M::measure(text.as_str());
+        if (MIN..=MAX).contains(&measure) {
+            Ok(Bounded {
+                measure: PhantomData,
+                text,
+            })
+        }
+        else {
+            Err(OutOfBoundsError {
+                text,
+                measure,
+                min: MIN,
+                max: MAX,
+            })
+        }
+    }
+
+    /// Gets the length of the text per the measure `M`.
+    pub fn measure(&self) -> usize {
+        M::measure(self.text.as_str())
+    }
+
+    pub fn into_text_buf(self) -> Legible<String, P> {
+        self.text
+    }
+
+    pub fn into_string(self) -> String {
+        self.text.into_string1().into_string()
+    }
+
+    pub fn as_text_buf(&self) -> &Legible<String, P> {
+        &self.text
+    }
+
+    pub fn as_text(&self) -> &Legible<str, P> {
+        self.text.as_text()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> AsRef<str> for Bounded<MIN, MAX, M, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &str {
+        self.text.as_str()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> AsRef<Legible<str, P>> for Bounded<MIN, MAX, M, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &Legible<str, P> {
+        self.as_text()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> Clone for Bounded<MIN, MAX, M, P> {
+    fn clone(&self) -> Self {
+        Bounded {
+            measure: PhantomData,
+            text: self.text.clone(),
+        }
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> Debug for Bounded<MIN, MAX, M, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("Bounded")
+            .field(&self.text.as_str())
+            .finish()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> Deref for Bounded<MIN, MAX, M, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    type Target = Legible<str, P>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_text()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> Display for Bounded<MIN, MAX, M, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.text.as_str())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> Eq for Bounded<MIN, MAX, M, P> {}
+
+impl<const MIN: usize, const MAX: usize, M, P> From<Bounded<MIN, MAX, M, P>> for Legible<String, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    fn from(text: Bounded<MIN, MAX, M, P>) -> Self {
+        text.into_text_buf()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> From<Bounded<MIN, MAX, M, P>> for String
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    fn from(text: Bounded<MIN, MAX, M, P>) -> Self {
+        text.into_string()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> Hash for Bounded<MIN, MAX, M, P> {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.text.hash(state)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> Ord for Bounded<MIN, MAX, M, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.text.cmp(&other.text)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> PartialEq for Bounded<MIN, MAX, M, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.text.eq(&other.text)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> PartialOrd for Bounded<MIN, MAX, M, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, const MIN: usize, const MAX: usize, M, P> TryFrom<&'a str> for Bounded<MIN, MAX, M, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    type Error = BoundedError<&'a str>;
+
+    fn try_from(text: &'a str) -> Result<Self, Self::Error> {
+        let buf = Legible::try_from(text)?;
+        Bounded::try_from_text_buf(buf).map_err(|error| error.map(|_| text).into())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> TryFrom<String> for Bounded<MIN, MAX, M, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    type Error = BoundedError<String>;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        let buf = Legible::try_from(text)?;
+        Bounded::try_from_text_buf(buf)
+            .map_err(|error| error.map(|text| text.into_string1().into_string()).into())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M, P> TryFrom<Legible<String, P>>
+    for Bounded<MIN, MAX, M, P>
+where
+    M: Measure,
+    P: LegibilityPolicy,
+{
+    type Error = OutOfBoundsError<Legible<String, P>>;
+
+    fn try_from(text: Legible<String, P>) -> Result<Self, Self::Error> {
+        Bounded::try_from_text_buf(text)
+    }
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct OutOfBoundsError<T> {
+    text: T,
+    measure: usize,
+    min: usize,
+    max: usize,
+}
+
+impl<T> OutOfBoundsError<T> {
+    pub fn into_text(self) -> T {
+        self.text
+    }
+
+    fn map<U, F>(self, f: F) -> OutOfBoundsError<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        OutOfBoundsError {
+            text: f(self.text),
+            measure: self.measure,
+            min: self.min,
+            max: self.max,
+        }
+    }
+
+    pub fn as_text(&self) -> &T {
+        &self.text
+    }
+
+    /// Gets the length of the text that is out of bounds.
+    pub fn measure(&self) -> usize {
+        self.measure
+    }
+
+    pub fn bounds(&self) -> RangeInclusive<usize> {
+        self.min..=self.max
+    }
+}
+
+impl<T> Debug for OutOfBoundsError<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("OutOfBoundsError")
+            .field("measure", &self.measure)
+            .field("bounds", &self.bounds())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for OutOfBoundsError<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "failed to construct bounded text: length {} is not in {}..={}",
+            self.measure, self.min, self.max,
+        )
+    }
+}
+
+impl<T> Error for OutOfBoundsError<T> {}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub enum BoundedError<T> {
+    Illegible(IllegibleError<T>),
+    OutOfBounds(OutOfBoundsError<T>),
+}
+
+impl<T> BoundedError<T> {
+    pub fn into_text(self) -> T {
+        match self {
+            BoundedError::Illegible(error) => error.into_illegible(),
+            BoundedError::OutOfBounds(error) => error.into_text(),
+        }
+    }
+}
+
+impl<T> Debug for BoundedError<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BoundedError::Illegible(error) => {
+                formatter.debug_tuple("Illegible").field(error).finish()
+            },
+            BoundedError::OutOfBounds(error) => {
+                formatter.debug_tuple("OutOfBounds").field(error).finish()
+            },
+        }
+    }
+}
+
+impl<T> Display for BoundedError<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BoundedError::Illegible(error) => write!(formatter, "{error}"),
+            BoundedError::OutOfBounds(error) => write!(formatter, "{error}"),
+        }
+    }
+}
+
+impl<T> Error for BoundedError<T> {}
+
+impl<T> From<IllegibleError<T>> for BoundedError<T> {
+    fn from(error: IllegibleError<T>) -> Self {
+        BoundedError::Illegible(error)
+    }
+}
+
+impl<T> From<OutOfBoundsError<T>> for BoundedError<T> {
+    fn from(error: OutOfBoundsError<T>) -> Self {
+        BoundedError::OutOfBounds(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+    #[cfg(feature = "serde")]
+    use {alloc::string::String, alloc::vec::Vec, serde_test::Token};
+
+    use crate::text::{BoundedError, BoundedText, BoundedWidthCjkText, BoundedWidthText};
+    #[cfg(feature = "serde")]
+    use {crate::serde, crate::serde::harness::legible};
+
+    #[rstest]
+    #[case::min("a")]
+    #[case::max("abc")]
+    #[case::combining("a\u{301}b\u{301}c\u{301}")]
+    #[case::emoji("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}")]
+    fn try_from_str_in_grapheme_bounds_then_bounded_text(#[case] text: &str) {
+        let bounded = BoundedText::<1, 3>::try_from(text).unwrap();
+        assert_eq!(bounded.as_text(), text);
+    }
+
+    #[rstest]
+    #[case::too_long("abcd", 4)]
+    #[case::too_long_with_illegible_prefix("\u{200B}abc", 4)]
+    fn try_from_str_out_of_grapheme_bounds_then_out_of_bounds_error(
+        #[case] text: &str,
+        #[case] expected: usize,
+    ) {
+        let Err(BoundedError::OutOfBounds(error)) = BoundedText::<1, 3>::try_from(text)
+        else {
+            panic!("expected out of bounds error");
+        };
+        assert_eq!(error.measure(), expected);
+        assert_eq!(error.bounds(), 1..=3);
+    }
+
+    #[rstest]
+    fn try_from_illegible_str_then_illegible_error() {
+        assert!(matches!(
+            BoundedText::<1, 3>::try_from("\u{200B}"),
+            Err(BoundedError::Illegible(_)),
+        ));
+    }
+
+    #[rstest]
+    #[case::latin("abcd", true)]
+    #[case::japanese("練習", true)]
+    #[case::japanese_too_wide("練習a", false)]
+    fn try_from_str_with_width_bounds_then_is_ok_eq(#[case] text: &str, #[case] expected: bool) {
+        assert_eq!(BoundedWidthText::<2, 4>::try_from(text).is_ok(), expected);
+    }
+
+    #[rstest]
+    #[case::latin("abcd", true)]
+    #[case::ambiguous("±①", true)]
+    #[case::ambiguous_too_wide("±①a", false)]
+    fn try_from_str_with_cjk_width_bounds_then_is_ok_eq(
+        #[case] text: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            BoundedWidthCjkText::<2, 4>::try_from(text).is_ok(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn de_serialize_bounded_text_into_and_from_tokens_eq(legible: impl Iterator<Item = Token>) {
+        let text = BoundedText::<1, 8>::try_from("legible").unwrap();
+        serde::harness::assert_into_and_from_tokens_eq::<_, Vec<_>>(text, legible);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::too_long("legible")]
+    #[case::illegible("\u{FEFF}")]
+    fn deserialize_bounded_text_from_invalid_tokens_then_error(#[case] text: &'static str) {
+        let error = BoundedText::<1, 3>::try_from(String::from(text)).unwrap_err();
+        serde_test::assert_de_tokens_error::<BoundedText<1, 3>>(
+            &[Token::BorrowedStr(text)],
+            &std::string::ToString::to_string(&error),
+        );
+    }
+}
@@ -9,27 +9,36 @@ use alloc::borrow::{Borrow, BorrowMut, Cow};
 use alloc::boxed::Box;
 use alloc::string::String;
 use core::fmt::{self, Debug, Display, Formatter};
-use core::ops::{Deref, DerefMut, RangeTo};
-use core::slice::SliceIndex;
+use core::iter;
+use core::ops::{Bound, Deref, DerefMut, Range, RangeBounds, RangeTo};
 use mitsein::borrow1::CowStr1;
 use mitsein::boxed1::BoxedStr1;
 use mitsein::str1::Str1;
 use mitsein::string1::String1;
 use mitsein::Segmentation;
+use unicode_segmentation::GraphemeCursor;
 
-use crate::grapheme::GraphemeBuf;
-use crate::text::Text;
-use crate::{IllegibleError, Legible, StrExt as _};
+use crate::grapheme::{Grapheme, GraphemeBuf};
+use crate::policy::{LegibilityPolicy, NonBlank, SingleLine, Standard};
+use crate::text::{Line, Text, VisibleText};
+use crate::{diagnose, IllegibleError, Legible, StrExt as _, Visible};
 
 pub type BoxedText = Box<Text>;
 
+pub type BoxedVisibleText = Box<VisibleText>;
+
+pub type BoxedLine = Box<Line>;
+
 pub trait BoxedTextExt {
     fn from_boxed_str1_unchecked(text: BoxedStr1) -> Self;
 
     fn into_boxed_str1(self) -> BoxedStr1;
 }
 
-impl BoxedTextExt for BoxedText {
+impl<P> BoxedTextExt for Box<Legible<str, P>>
+where
+    P: LegibilityPolicy,
+{
     fn from_boxed_str1_unchecked(text: BoxedStr1) -> Self {
         let text = Box::into_raw(text);
         // SAFETY: Client code is responsible for asserting that the input string has legible text.
@@ -37,7 +46,7 @@ impl BoxedTextExt for BoxedText {
         //         representation (`Text` is `repr(transparent)`). Moreover, the allocator only
         //         requires that the memory location and layout are the same when deallocating, so
         //         dropping the transmuted `Box` is sound.
-        unsafe { Box::from_raw(text as *mut Text) }
+        unsafe { Box::from_raw(text as *mut Legible<str, P>) }
     }
 
     fn into_boxed_str1(self) -> BoxedStr1 {
@@ -52,47 +61,80 @@ impl BoxedTextExt for BoxedText {
 
 pub type CowText<'a> = Cow<'a, Text>;
 
+pub type CowVisibleText<'a> = Cow<'a, VisibleText>;
+
+pub type CowLine<'a> = Cow<'a, Line>;
+
 pub trait CowTextExt<'a> {}
 
-impl<'a> CowTextExt<'a> for CowText<'a> {}
+impl<'a, P> CowTextExt<'a> for Cow<'a, Legible<str, P>> where P: LegibilityPolicy {}
+
+pub type Pop<'t, T, P = Standard> = Take<'t, T, RangeTo<usize>, P>;
+
+pub type Insert<'t, 'i, P = Standard> = Take<'t, (), (usize, &'i str), P>;
+
+pub type Remove<'t, T, P = Standard> = Take<'t, T, Range<usize>, P>;
 
-pub type Pop<'t, T> = Take<'t, T, RangeTo<usize>>;
+pub type Retain<'t, P = Standard> = Take<'t, (), String, P>;
 
-#[derive(Debug)]
-pub struct Take<'t, T, N = ()> {
-    text: &'t mut TextBuf,
+pub struct Take<'t, T, N = (), P = Standard> {
+    text: &'t mut Legible<String, P>,
     remainder: N,
-    many: fn(&'t mut TextBuf, N) -> T,
+    // Determines whether or not the text remains legible if the remainder is taken. This function
+    // may edit the text, but must restore it before returning.
+    is_legible: fn(&mut Legible<String, P>, &N) -> bool,
+    many: fn(&'t mut Legible<String, P>, N) -> T,
 }
 
-impl<'t, T, N> Take<'t, T, N> {
-    const fn with(text: &'t mut TextBuf, remainder: N, many: fn(&mut TextBuf, N) -> T) -> Self {
+impl<'t, T, N, P> Take<'t, T, N, P> {
+    const fn with_legibility(
+        text: &'t mut Legible<String, P>,
+        remainder: N,
+        is_legible: fn(&mut Legible<String, P>, &N) -> bool,
+        many: fn(&mut Legible<String, P>, N) -> T,
+    ) -> Self {
         Take {
             text,
             remainder,
+            is_legible,
             many,
         }
     }
 }
 
-impl<'t, T, N> Take<'t, T, N>
+impl<'t, T, P> Take<'t, T, RangeTo<usize>, P>
+where
+    P: LegibilityPolicy,
+{
+    fn with(
+        text: &'t mut Legible<String, P>,
+        remainder: RangeTo<usize>,
+        many: fn(&mut Legible<String, P>, RangeTo<usize>) -> T,
+    ) -> Self {
+        Take::with_legibility(
+            text,
+            remainder,
+            |text, remainder| text.has_legible_prefix(remainder.end),
+            many,
+        )
+    }
+}
+
+impl<'t, T, N, P> Take<'t, T, N, P>
 where
-    N: Clone + SliceIndex<str, Output = str>,
+    P: LegibilityPolicy,
 {
     fn take_or_else<E, F>(self, one: F) -> Result<T, E>
     where
-        F: FnOnce(&'t mut TextBuf, N) -> E,
+        F: FnOnce(&'t mut Legible<String, P>, N) -> E,
     {
         let Take {
             text,
             remainder,
+            is_legible,
             many,
         } = self;
-        if text
-            .get(remainder.clone())
-            .expect("string slice out of bounds or not on code point boundary")
-            .has_legible_text()
-        {
+        if is_legible(text, &remainder) {
             Ok(many(text, remainder))
         }
         else {
@@ -116,7 +158,10 @@ where
     }
 }
 
-impl<'t, T> Take<'t, T, RangeTo<usize>> {
+impl<'t, T, P> Take<'t, T, RangeTo<usize>, P>
+where
+    P: LegibilityPolicy,
+{
     pub fn or_get(self) -> Result<T, &'t str> {
         self.take_or_else(|text, remainder| {
             // `take_or_else` attempts to slice the string, so `[]` is used here instead of `get`
@@ -126,18 +171,94 @@ impl<'t, T> Take<'t, T, RangeTo<usize>> {
     }
 }
 
+impl<'t, 'i, P> Take<'t, (), (usize, &'i str), P>
+where
+    P: LegibilityPolicy,
+{
+    pub fn or_get(self) -> Result<(), &'i str> {
+        self.take_or_else(|_, (_, inserted)| inserted)
+    }
+}
+
+impl<'t, T, P> Take<'t, T, Range<usize>, P>
+where
+    P: LegibilityPolicy,
+{
+    pub fn or_get(self) -> Result<T, &'t str> {
+        self.take_or_else(|text, range| &text[range])
+    }
+}
+
+impl<'t, P> Take<'t, (), String, P>
+where
+    P: LegibilityPolicy,
+{
+    pub fn or_get(self) -> Result<(), String> {
+        self.take_or_else(|_, retained| retained)
+    }
+}
+
+impl<T, N, P> Debug for Take<'_, T, N, P>
+where
+    N: Debug,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Take")
+            .field("text", &self.text)
+            .field("remainder", &self.remainder)
+            .finish_non_exhaustive()
+    }
+}
+
 pub type TextBuf = Legible<String>;
 
-impl TextBuf {
+pub type VisibleTextBuf = Visible<String>;
+
+pub type LineBuf = Legible<String, SingleLine>;
+
+impl<P> Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     pub const fn from_string1_unchecked(text: String1) -> Self {
-        TextBuf { text }
+        Legible::from_non_empty_unchecked(text)
+    }
+
+    /// Constructs legible text from the given string slice with invisible code points removed.
+    ///
+    /// See [`StrExt::strip_invisible`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stripped text is illegible per the policy `P`.
+    ///
+    /// [`StrExt::strip_invisible`]: crate::StrExt::strip_invisible
+    pub fn try_from_stripped(text: &str) -> Result<Self, IllegibleError<String>> {
+        Legible::try_from(text.strip_invisible().into_owned())
+    }
+
+    /// Constructs legible text from the given string slice with excess combining marks removed.
+    ///
+    /// This can be used to sanitize text for policies that limit combining marks, such as
+    /// [`MaxCombiningMarks`]. See [`StrExt::limit_combining_marks`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the limited text is illegible per the policy `P`.
+    ///
+    /// [`MaxCombiningMarks`]: crate::policy::MaxCombiningMarks
+    /// [`StrExt::limit_combining_marks`]: crate::StrExt::limit_combining_marks
+    pub fn try_from_limited(text: &str, n: usize) -> Result<Self, IllegibleError<String>> {
+        Legible::try_from(text.limit_combining_marks(n).into_owned())
     }
 
     pub fn into_string1(self) -> String1 {
         self.text
     }
 
-    pub fn pop_char(&mut self) -> Pop<'_, char> {
+    pub fn pop_char(&mut self) -> Pop<'_, char, P> {
         let (index, _) = self.char_indices1().rev().first();
         // `TakeOr` only calls this function if the range has text. Since `index` demarks the last
         // code point and the exclusive end of the range, there must be a terminating code point
@@ -150,7 +271,7 @@ impl TextBuf {
         })
     }
 
-    pub fn pop_grapheme(&mut self) -> Pop<'_, GraphemeBuf> {
+    pub fn pop_grapheme(&mut self) -> Pop<'_, GraphemeBuf, P> {
         let (index, _) = self.grapheme_indices1().rev().first();
         // SAFETY: `index` demarks a grapheme and `TakeOr` only calls this function if the
         //         range is a valid string slice and has text, so splitting off the grapheme
@@ -165,16 +286,287 @@ impl TextBuf {
         })
     }
 
-    pub fn leak<'a>(self) -> &'a Text {
-        Text::from_str1_unchecked(self.text.leak())
+    /// Appends a string slice to the text.
+    pub fn push_str<'i>(&mut self, text: &'i str) -> Insert<'_, 'i, P> {
+        let index = self.len().get();
+        Legible::insert_str_at(self, index, text)
+    }
+
+    /// Appends a grapheme cluster to the text.
+    pub fn push_grapheme<'i>(&mut self, grapheme: &'i Grapheme) -> Insert<'_, 'i, P> {
+        self.push_str(grapheme.as_str())
+    }
+
+    /// Inserts a string slice before the grapheme cluster at `index`.
+    ///
+    /// The text is appended if `index` is the number of grapheme clusters in the text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of grapheme clusters in the text.
+    pub fn insert_str<'i>(&mut self, index: usize, text: &'i str) -> Insert<'_, 'i, P> {
+        let index = self.grapheme_offset(index);
+        Legible::insert_str_at(self, index, text)
+    }
+
+    /// Shortens the text to its first `len` grapheme clusters.
+    ///
+    /// The text is unchanged if `len` is greater than or equal to its number of grapheme
+    /// clusters.
+    pub fn truncate_graphemes(&mut self, len: usize) -> Take<'_, (), RangeTo<usize>, P> {
+        let end = self.grapheme_offsets().nth(len).unwrap_or(self.len().get());
+        Take::with(self, ..end, |text, remainder| {
+            text.split_off_unchecked(remainder.end);
+        })
+    }
+
+    /// Removes the grapheme cluster at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_grapheme(&mut self, index: usize) -> Remove<'_, GraphemeBuf, P> {
+        let (start, grapheme) = self
+            .grapheme_indices()
+            .nth(index)
+            .expect("grapheme index out of bounds");
+        let range = start..(start + grapheme.as_str().len());
+        Take::with_legibility(self, range, Legible::is_legible_without, |text, range| {
+            // SAFETY: `range` demarks a grapheme, so the removed string is a grapheme cluster.
+            unsafe { GraphemeBuf::from_string_unchecked(text.remove_unchecked(range)) }
+        })
+    }
+
+    /// Removes the grapheme clusters in the given range of grapheme cluster indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting index is greater than the ending index or if the ending index is
+    /// greater than the number of grapheme clusters in the text.
+    pub fn drain_graphemes<R>(&mut self, range: R) -> Remove<'_, String, P>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("grapheme index out of bounds"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => {
+                Some(end.checked_add(1).expect("grapheme index out of bounds"))
+            },
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => None,
+        };
+        assert!(
+            end.is_none_or(|end| start <= end),
+            "grapheme range starts after it ends",
+        );
+        let start = self.grapheme_offset(start);
+        let end = match end {
+            Some(end) => self.grapheme_offset(end),
+            _ => self.len().get(),
+        };
+        Take::with_legibility(
+            self,
+            start..end,
+            Legible::is_legible_without,
+            |text, range| text.remove_unchecked(range),
+        )
+    }
+
+    /// Retains only the grapheme clusters for which the given predicate returns `true`.
+    pub fn retain_graphemes<F>(&mut self, mut f: F) -> Retain<'_, P>
+    where
+        F: FnMut(&Grapheme) -> bool,
+    {
+        let retained = self.graphemes().filter(|grapheme| f(grapheme)).fold(
+            String::with_capacity(self.len().get()),
+            |mut retained, grapheme| {
+                retained.push_str(grapheme.as_str());
+                retained
+            },
+        );
+        Take::with_legibility(
+            self,
+            retained,
+            |_, retained| retained.has_legible_text_with::<P>(),
+            |text, retained| {
+                // SAFETY: `Take` only calls this function if `retained` has legible text, which
+                //         implies that it is non-empty.
+                text.text = unsafe { String1::from_string_unchecked(retained) };
+            },
+        )
+    }
+
+    /// Edits the text as a [`String`] and then checks that it is still legible.
+    ///
+    /// The function `f` edits a copy of the text. If the edited text is legible, then it replaces
+    /// the text and the output of `f` is returned. Otherwise, the text is unchanged and an error
+    /// with the illegible edited text is returned.
+    pub fn try_edit<T, F>(&mut self, f: F) -> Result<T, IllegibleError<String>>
+    where
+        F: FnOnce(&mut String) -> T,
+    {
+        let mut text = String::from(self.as_str());
+        let output = f(&mut text);
+        *self = Legible::try_from(text)?;
+        Ok(output)
+    }
+
+    pub fn leak<'a>(self) -> &'a Legible<str, P> {
+        Legible::from_str1_unchecked(self.text.leak())
+    }
+
+    pub fn as_text(&self) -> &Legible<str, P> {
+        Legible::from_str1_unchecked(self.text.as_str1())
+    }
+
+    pub fn as_mut_text(&mut self) -> &mut Legible<str, P> {
+        Legible::from_mut_str1_unchecked(self.text.as_mut_str1())
+    }
+
+    fn insert_str_at<'i>(&mut self, index: usize, text: &'i str) -> Insert<'_, 'i, P> {
+        Take::with_legibility(
+            self,
+            (index, text),
+            |text, &(index, inserted)| {
+                text.insert_unchecked(index, inserted);
+                let is_legible = text.as_str().has_legible_text_with::<P>();
+                text.remove_unchecked(index..(index + inserted.len()));
+                is_legible
+            },
+            |text, (index, inserted)| text.insert_unchecked(index, inserted),
+        )
+    }
+
+    // Determines whether or not the text has a legible grapheme cluster before the given byte
+    // index, which must be on a grapheme cluster boundary.
+    //
+    // The text is legible, so if the grapheme clusters from `start` to the end of the text are
+    // illegible, then there must be a legible grapheme cluster before `start`. Only these trailing
+    // grapheme clusters are examined in that case, which is the common case when popping.
+    // Otherwise, the grapheme clusters before `start` are examined alternately from the front and
+    // the back until a legible grapheme cluster is found. Each run of illegible grapheme clusters
+    // is typically scanned this way only once as text is drained from its end, so draining text
+    // is linear in its length.
+    fn has_legible_grapheme_before(&self, start: usize) -> bool {
+        let text = self.as_str();
+        if !text[start..].graphemes().any(P::is_legible_grapheme) {
+            return true;
+        }
+        let mut graphemes = text[..start].graphemes();
+        while let Some(front) = graphemes.next() {
+            if P::is_legible_grapheme(front)
+                || graphemes.next_back().is_some_and(P::is_legible_grapheme)
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    // Determines whether or not the prefix of the text that ends at the given byte index is
+    // legible. This does not scan the entire prefix: only the grapheme cluster that `end` splits
+    // is checked for admissibility and intact grapheme clusters are examined only until a legible
+    // grapheme cluster is found.
+    fn has_legible_prefix(&self, end: usize) -> bool {
+        let text = self.as_str();
+        assert!(
+            text.is_char_boundary(end),
+            "string index not on code point boundary",
+        );
+        // Find the start of the grapheme cluster that `end` splits, if any. Grapheme clusters
+        // before this index are intact in the prefix.
+        let mut cursor = GraphemeCursor::new(end, text.len(), true);
+        let start = if cursor
+            .is_boundary(text, 0)
+            .expect("grapheme cursor chunk is incomplete")
+        {
+            end
+        }
+        else {
+            cursor
+                .prev_boundary(text, 0)
+                .expect("grapheme cursor chunk is incomplete")
+                .unwrap_or(0)
+        };
+        // The intact grapheme clusters are admissible, because the text is legible. Only the
+        // split grapheme cluster must be checked for admissibility.
+        let (is_admissible, has_legible_split) = text[start..end].graphemes().fold(
+            (true, false),
+            |(is_admissible, has_legible), grapheme| {
+                (
+                    is_admissible && P::is_admissible_grapheme(grapheme),
+                    has_legible || P::is_legible_grapheme(grapheme),
+                )
+            },
+        );
+        is_admissible && (has_legible_split || self.has_legible_grapheme_before(start))
+    }
+
+    // Determines whether or not the text is legible without the given byte range. The text is
+    // edited, but restored before returning. If the range extends to the end of the text, then
+    // only the remaining prefix is examined (see `has_legible_prefix`).
+    fn is_legible_without(&mut self, range: &Range<usize>) -> bool {
+        if range.end == self.len().get() {
+            return self.has_legible_prefix(range.start);
+        }
+        // SAFETY: Bytes are only rotated within the string and are restored before returning.
+        //         The slice of the string that is checked for legibility is composed of valid
+        //         UTF-8, because `range` is on code point boundaries.
+        let bytes = unsafe { self.as_mut_string1().as_mut_vec1() };
+        let end = bytes.len().get() - range.len();
+        bytes[range.start..].rotate_left(range.len());
+        let is_legible =
+            unsafe { core::str::from_utf8_unchecked(&bytes[..end]) }.has_legible_text_with::<P>();
+        bytes[range.start..].rotate_right(range.len());
+        is_legible
+    }
+
+    // Inserts a string slice at the given byte index. This does not check legibility.
+    fn insert_unchecked(&mut self, index: usize, text: &str) {
+        assert!(
+            self.is_char_boundary(index),
+            "string index not on code point boundary",
+        );
+        // SAFETY: `text` is valid UTF-8 and is inserted on a code point boundary.
+        let bytes = unsafe { self.as_mut_string1().as_mut_vec1() };
+        bytes.extend_from_slice(text.as_bytes());
+        bytes[index..].rotate_right(text.len());
     }
 
-    pub fn as_text(&self) -> &Text {
-        Text::from_str1_unchecked(self.text.as_str1())
+    // Removes the given byte range from the text. This does not check legibility, but the range
+    // must not span the entire text.
+    fn remove_unchecked(&mut self, range: Range<usize>) -> String {
+        // SAFETY: `range` is on code point boundaries, so rotating the removed bytes to the end
+        //         of the string and splitting them off produces valid UTF-8 on both sides.
+        let bytes = unsafe { self.as_mut_string1().as_mut_vec1() };
+        let end = bytes.len().get() - range.len();
+        bytes[range.start..].rotate_left(range.len());
+        unsafe { String::from_utf8_unchecked(bytes.segment(end..).split_off(0)) }
     }
 
-    pub fn as_mut_text(&mut self) -> &mut Text {
-        Text::from_mut_str1_unchecked(self.text.as_mut_str1())
+    // Splits off the text at the given byte index. This does not check legibility, but `index`
+    // must be non-zero.
+    fn split_off_unchecked(&mut self, index: usize) -> String {
+        let end = self.len().get();
+        self.remove_unchecked(index..end)
+    }
+
+    // Gets the byte offset of the grapheme cluster at the given index or the length of the text if
+    // the index is the number of grapheme clusters.
+    fn grapheme_offset(&self, index: usize) -> usize {
+        self.grapheme_offsets()
+            .nth(index)
+            .expect("grapheme index out of bounds")
+    }
+
+    fn grapheme_offsets(&self) -> impl '_ + Iterator<Item = usize> {
+        self.grapheme_indices()
+            .map(|(index, _)| index)
+            .chain(iter::once(self.len().get()))
     }
 
     const fn as_string1(&self) -> &String1 {
@@ -186,83 +578,137 @@ impl TextBuf {
     }
 }
 
-impl AsMut<Str1> for TextBuf {
+impl<P> AsMut<Str1> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn as_mut(&mut self) -> &mut Str1 {
         self.as_mut_text().as_mut_str1()
     }
 }
 
-impl AsMut<Text> for TextBuf {
-    fn as_mut(&mut self) -> &mut Text {
+impl<P> AsMut<Legible<str, P>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn as_mut(&mut self) -> &mut Legible<str, P> {
         self.as_mut_text()
     }
 }
 
-impl AsRef<Str1> for TextBuf {
+impl<P> AsRef<Str1> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn as_ref(&self) -> &Str1 {
         self.as_text().as_str1()
     }
 }
 
-impl AsRef<Text> for TextBuf {
-    fn as_ref(&self) -> &Text {
+impl<P> AsRef<Legible<str, P>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &Legible<str, P> {
         self.as_text()
     }
 }
 
-impl Borrow<Text> for TextBuf {
-    fn borrow(&self) -> &Text {
+impl<P> Borrow<Legible<str, P>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn borrow(&self) -> &Legible<str, P> {
         self.as_text()
     }
 }
 
-impl BorrowMut<Text> for TextBuf {
-    fn borrow_mut(&mut self) -> &mut Text {
+impl<P> BorrowMut<Legible<str, P>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn borrow_mut(&mut self) -> &mut Legible<str, P> {
         self.as_mut_text()
     }
 }
 
-impl Debug for TextBuf {
+impl<P> Debug for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
-        write!(formatter, "{:?}", self.as_str())
+        Debug::fmt(self.as_text(), formatter)
     }
 }
 
-impl Deref for TextBuf {
-    type Target = Text;
+impl<P> Deref for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    type Target = Legible<str, P>;
 
     fn deref(&self) -> &Self::Target {
         self.as_text()
     }
 }
 
-impl DerefMut for TextBuf {
+impl<P> DerefMut for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut_text()
     }
 }
 
-impl Display for TextBuf {
+impl<P> Display for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         write!(formatter, "{}", self.as_str())
     }
 }
 
-impl From<BoxedText> for TextBuf {
-    fn from(text: BoxedText) -> Self {
-        TextBuf::from_string1_unchecked(String1::from(text.into_boxed_str1()))
+impl<P> From<Box<Legible<str, P>>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: Box<Legible<str, P>>) -> Self {
+        Legible::from_string1_unchecked(String1::from(text.into_boxed_str1()))
+    }
+}
+
+impl<'a, P> From<&'a Legible<str, P>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: &'a Legible<str, P>) -> Self {
+        Legible::from_string1_unchecked(String1::from(text.as_str1()))
     }
 }
 
-impl<'a> From<&'a Text> for TextBuf {
-    fn from(text: &'a Text) -> Self {
-        TextBuf::from_string1_unchecked(String1::from(text.as_str1()))
+impl<P> From<Legible<String, NonBlank<P>>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: Legible<String, NonBlank<P>>) -> Self {
+        Legible::from_string1_unchecked(text.into_string1())
     }
 }
 
-impl<T> PartialEq<&'_ T> for TextBuf
+impl<P> From<Legible<String, SingleLine<P>>> for Legible<String, P>
 where
-    TextBuf: PartialEq<T>,
+    P: LegibilityPolicy,
+{
+    fn from(text: Legible<String, SingleLine<P>>) -> Self {
+        Legible::from_string1_unchecked(text.into_string1())
+    }
+}
+
+impl<T, P> PartialEq<&'_ T> for Legible<String, P>
+where
+    Legible<String, P>: PartialEq<T>,
     T: ?Sized,
 {
     fn eq(&self, other: &&'_ T) -> bool {
@@ -270,87 +716,121 @@ where
     }
 }
 
-impl PartialEq<Cow<'_, str>> for TextBuf {
+impl<P> PartialEq<Cow<'_, str>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn eq(&self, other: &Cow<'_, str>) -> bool {
         self.as_str().eq(other.as_ref())
     }
 }
 
-impl PartialEq<CowStr1<'_>> for TextBuf {
+impl<P> PartialEq<CowStr1<'_>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn eq(&self, other: &CowStr1<'_>) -> bool {
         self.as_str1().eq(other.as_ref())
     }
 }
 
-impl PartialEq<CowText<'_>> for TextBuf {
-    fn eq(&self, other: &CowText<'_>) -> bool {
+impl<P> PartialEq<Cow<'_, Legible<str, P>>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn eq(&self, other: &Cow<'_, Legible<str, P>>) -> bool {
         self.as_text().eq(other.as_ref())
     }
 }
 
-impl PartialEq<str> for TextBuf {
+impl<P> PartialEq<str> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn eq(&self, other: &str) -> bool {
         self.as_str().eq(other)
     }
 }
 
-impl PartialEq<Str1> for TextBuf {
+impl<P> PartialEq<Str1> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn eq(&self, other: &Str1) -> bool {
         self.as_str1().eq(other)
     }
 }
 
-impl PartialEq<String1> for TextBuf {
+impl<P> PartialEq<String1> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     fn eq(&self, other: &String1) -> bool {
         self.as_string1().eq(other)
     }
 }
 
-impl PartialEq<Text> for TextBuf {
-    fn eq(&self, other: &Text) -> bool {
+impl<P> PartialEq<Legible<str, P>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn eq(&self, other: &Legible<str, P>) -> bool {
         self.as_text().eq(other)
     }
 }
 
-impl<'a> TryFrom<&'a str> for TextBuf {
+impl<'a, P> TryFrom<&'a str> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     type Error = IllegibleError<&'a str>;
 
     fn try_from(text: &'a str) -> Result<Self, Self::Error> {
         String1::try_from(text)
-            .map_err(IllegibleError::from_illegible)
-            .and_then(|text1| TextBuf::try_from(text1).map_err(|error| error.map(|_| text)))
+            .map_err(IllegibleError::from_empty)
+            .and_then(|text1| Legible::try_from(text1).map_err(|error| error.map(|_| text)))
     }
 }
 
-impl TryFrom<String> for TextBuf {
+impl<P> TryFrom<String> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     type Error = IllegibleError<String>;
 
     fn try_from(text: String) -> Result<Self, Self::Error> {
         String1::try_from(text)
-            .map_err(IllegibleError::from_illegible)
+            .map_err(IllegibleError::from_empty)
             .and_then(|text1| {
-                TextBuf::try_from(text1).map_err(|error| error.map(String1::into_string))
+                Legible::try_from(text1).map_err(|error| error.map(String1::into_string))
             })
     }
 }
 
-impl<'a> TryFrom<&'a Str1> for TextBuf {
+impl<'a, P> TryFrom<&'a Str1> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     type Error = IllegibleError<&'a Str1>;
 
     fn try_from(text: &'a Str1) -> Result<Self, Self::Error> {
-        TextBuf::try_from(String1::from(text)).map_err(|error| error.map(|_| text))
+        Legible::try_from(String1::from(text)).map_err(|error| error.map(|_| text))
     }
 }
 
-impl TryFrom<String1> for TextBuf {
+impl<P> TryFrom<String1> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
     type Error = IllegibleError<String1>;
 
     fn try_from(text: String1) -> Result<Self, Self::Error> {
-        if text.has_legible_text() {
-            Ok(TextBuf::from_string1_unchecked(text))
+        if text.has_legible_text_with::<P>() {
+            Ok(Legible::from_string1_unchecked(text))
         }
         else {
-            Err(IllegibleError::from_illegible(text))
+            let diagnosis = diagnose::<P>(&text);
+            Err(IllegibleError::from_illegible(text, diagnosis))
         }
     }
 }
@@ -371,13 +851,51 @@ pub mod harness {
 mod tests {
     extern crate std;
 
+    use alloc::string::String;
+    use core::ops::Bound;
     use rstest::rstest;
     #[cfg(feature = "serde")]
     use {alloc::vec::Vec, serde_test::Token};
 
-    use crate::text::{Text, TextBuf};
+    use crate::diagnosis::Illegibility;
+    use crate::grapheme::Grapheme;
+    use crate::policy::MaxCombiningMarks;
+    use crate::text::{LineBuf, Text, TextBuf, VisibleText, VisibleTextBuf};
+    use crate::{Legible, StrExt as _};
     #[cfg(feature = "serde")]
-    use {crate::serde, crate::serde::harness::legible, crate::text::buf::harness::text};
+    use {
+        crate::policy::Strict, crate::serde, crate::serde::harness::legible,
+        crate::text::buf::harness::text,
+    };
+
+    #[rstest]
+    fn try_from_stripped_str_then_text_buf_eq() {
+        let text = TextBuf::try_from_stripped("\u{FEFF}leg\u{AD}ible\u{200B}").unwrap();
+        assert_eq!(text, "legible");
+    }
+
+    #[rstest]
+    fn try_from_stripped_invisible_str_then_illegible_error() {
+        let error = TextBuf::try_from_stripped("\u{200B}\u{2060}").unwrap_err();
+        assert_eq!(error.reason(), Illegibility::Empty);
+    }
+
+    #[rstest]
+    fn try_from_limited_str_then_text_buf_eq() {
+        let text = Legible::<String, MaxCombiningMarks<2>>::try_from_limited(
+            "Z\u{36B}\u{33F}\u{344}\u{300}algo",
+            2,
+        )
+        .unwrap();
+        assert_eq!(text, "Z\u{36B}\u{33F}algo");
+    }
+
+    #[rstest]
+    fn try_from_stacked_str_then_limited_illegible_error() {
+        let error = Legible::<String, MaxCombiningMarks<2>>::try_from("a\u{301}\u{302}\u{303}")
+            .unwrap_err();
+        assert_eq!(error.reason(), Illegibility::CombiningMarkLimit);
+    }
 
     #[rstest]
     #[case::only_one_char("A", "A")]
@@ -424,6 +942,202 @@ mod tests {
         assert_eq!(text, expected);
     }
 
+    #[rstest]
+    #[case::latin("legible", " text", "legible text")]
+    #[case::combining("e", "\u{301}", "e\u{301}")]
+    #[case::illegible("legible", "\u{200B}", "legible\u{200B}")]
+    fn push_str_onto_text_buf_then_text_buf_eq(
+        #[case] text: &str,
+        #[case] pushed: &str,
+        #[case] expected: &str,
+    ) {
+        let mut text = TextBuf::try_from(text).unwrap();
+        assert!(text.push_str(pushed).or_false());
+        assert_eq!(text, expected);
+    }
+
+    #[rstest]
+    fn push_line_break_onto_line_buf_then_line_buf_is_unchanged() {
+        let mut text = LineBuf::try_from("legible").unwrap();
+        assert_eq!(text.push_str("\n").or_get(), Err("\n"));
+        assert_eq!(text, "legible");
+        assert!(text
+            .push_grapheme(Grapheme::try_from_str("!").unwrap())
+            .or_false());
+        assert_eq!(text, "legible!");
+    }
+
+    #[rstest]
+    #[case::front(
+        0,
+        "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+        "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}ab"
+    )]
+    #[case::infix(1, "x", "axb")]
+    #[case::back(2, "x", "abx")]
+    fn insert_str_into_text_buf_then_text_buf_eq(
+        #[case] index: usize,
+        #[case] inserted: &str,
+        #[case] expected: &str,
+    ) {
+        let mut text = TextBuf::try_from("ab").unwrap();
+        assert!(text.insert_str(index, inserted).or_false());
+        assert_eq!(text, expected);
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn insert_str_into_text_buf_out_of_bounds_then_panic() {
+        let mut text = TextBuf::try_from("ab").unwrap();
+        let _ = text.insert_str(3, "x").or_false();
+    }
+
+    #[rstest]
+    #[case::shorten("a\u{301}bc", 2, true, "a\u{301}b")]
+    #[case::longer("abc", 7, true, "abc")]
+    #[case::to_illegible("\u{200B}abc", 1, false, "\u{200B}abc")]
+    #[case::to_empty("abc", 0, false, "abc")]
+    fn truncate_graphemes_of_text_buf_then_text_buf_eq(
+        #[case] text: &str,
+        #[case] len: usize,
+        #[case] is_truncated: bool,
+        #[case] expected: &str,
+    ) {
+        let mut text = TextBuf::try_from(text).unwrap();
+        assert_eq!(text.truncate_graphemes(len).or_false(), is_truncated);
+        assert_eq!(text, expected);
+    }
+
+    #[rstest]
+    fn remove_grapheme_from_text_buf_then_grapheme_eq() {
+        let mut text = TextBuf::try_from("a\u{301}b\u{200B}").unwrap();
+        assert_eq!(
+            text.remove_grapheme(0).or_none().unwrap().as_str(),
+            "a\u{301}"
+        );
+        assert_eq!(text, "b\u{200B}");
+        assert_eq!(text.remove_grapheme(0).or_get(), Err("b"));
+        assert_eq!(text, "b\u{200B}");
+    }
+
+    #[rstest]
+    #[case::prefix(..2, "ab", "c\u{200B}")]
+    #[case::infix(1..=1, "b", "ac\u{200B}")]
+    #[case::suffix(2.., "c\u{200B}", "ab")]
+    fn drain_graphemes_from_text_buf_then_drained_eq(
+        #[case] range: impl core::ops::RangeBounds<usize>,
+        #[case] drained: &str,
+        #[case] expected: &str,
+    ) {
+        let mut text = TextBuf::try_from("abc\u{200B}").unwrap();
+        assert_eq!(text.drain_graphemes(range).or_none().unwrap(), drained);
+        assert_eq!(text, expected);
+    }
+
+    #[rstest]
+    fn drain_all_graphemes_from_text_buf_then_text_buf_is_unchanged() {
+        let mut text = TextBuf::try_from("abc").unwrap();
+        assert_eq!(text.drain_graphemes(..).or_get(), Err("abc"));
+        assert_eq!(text, "abc");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "grapheme index out of bounds")]
+    fn drain_graphemes_after_max_index_from_text_buf_then_panic() {
+        let mut text = TextBuf::try_from("abc").unwrap();
+        let _ = text
+            .drain_graphemes((Bound::Excluded(usize::MAX), Bound::Unbounded))
+            .or_false();
+    }
+
+    #[rstest]
+    #[should_panic(expected = "grapheme index out of bounds")]
+    fn drain_graphemes_through_max_index_from_text_buf_then_panic() {
+        let mut text = TextBuf::try_from("abc").unwrap();
+        let _ = text.drain_graphemes(0..=usize::MAX).or_false();
+    }
+
+    #[rstest]
+    fn retain_graphemes_of_text_buf_then_text_buf_eq() {
+        let mut text = TextBuf::try_from("a1b2c3").unwrap();
+        assert!(text
+            .retain_graphemes(|grapheme| grapheme.chars1().first().is_alphabetic())
+            .or_false());
+        assert_eq!(text, "abc");
+        assert!(!text
+            .retain_graphemes(|grapheme| grapheme.chars1().first().is_numeric())
+            .or_false());
+        assert_eq!(text, "abc");
+    }
+
+    #[rstest]
+    fn retain_illegible_graphemes_of_text_buf_then_retained_eq() {
+        let mut text = TextBuf::try_from("a\u{200B}b\u{200C}").unwrap();
+        assert_eq!(
+            text.retain_graphemes(|grapheme| !grapheme.is_legible_text())
+                .or_get(),
+            Err(String::from("\u{200B}")),
+        );
+        assert_eq!(text, "a\u{200B}b\u{200C}");
+    }
+
+    #[rstest]
+    #[case::latin("legible")]
+    #[case::illegible_prefix("\u{200B}\u{200B}e\u{301}")]
+    #[case::illegible_prefix_and_suffix("\u{200B}\u{200B}\u{200B}ab\u{200B}\u{200B}")]
+    #[case::illegible_within("\u{200B}a\u{200B}\u{200B}\u{200B}b")]
+    #[case::legible_within("\u{200B}\u{200B}a\u{200B}\u{200B}")]
+    #[case::combining("e\u{301}\u{302}a")]
+    #[case::emoji("\u{200B}\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}")]
+    #[case::regional_indicators("\u{1F1FA}\u{1F1F8}\u{1F1E9}\u{1F1EA}")]
+    #[case::crlf("a\r\nb")]
+    fn has_legible_prefix_of_text_buf_then_eq_has_legible_text(#[case] text: &str) {
+        let text = TextBuf::try_from(text).unwrap();
+        for (end, _) in text.char_indices().skip(1) {
+            assert_eq!(
+                text.has_legible_prefix(end),
+                text[..end].has_legible_text(),
+                "prefix ending at {end}",
+            );
+        }
+    }
+
+    #[rstest]
+    fn try_edit_text_buf_into_illegible_text_then_text_buf_is_unchanged() {
+        let mut text = LineBuf::try_from("legible").unwrap();
+        let error = text.try_edit(|text| text.push_str("\ntext")).unwrap_err();
+        assert_eq!(error.reason(), Illegibility::LineBreak);
+        assert_eq!(error.into_illegible(), "legible\ntext");
+        assert_eq!(text, "legible");
+    }
+
+    #[rstest]
+    fn try_edit_text_buf_into_legible_text_then_output_and_text_buf_eq() {
+        let mut text = TextBuf::try_from("legible").unwrap();
+        let len = text
+            .try_edit(|text| {
+                text.insert_str(0, "il");
+                text.len()
+            })
+            .unwrap();
+        assert_eq!(len, 9);
+        assert_eq!(text, "illegible");
+    }
+
+    #[rstest]
+    #[case::whitespace_suffix("end  \t", "e")]
+    #[case::whitespace_prefix("  end", "  e")]
+    #[case::whitespace_infix("a b", "a")]
+    fn pop_grapheme_from_visible_text_buf_until_exhausted_then_visible_text_buf_eq(
+        #[case] text: &str,
+        #[case] expected: &str,
+    ) {
+        let mut text = VisibleTextBuf::try_from(text).unwrap();
+        let expected = VisibleText::try_from_str(expected).unwrap();
+        while text.pop_grapheme().or_false() {}
+        assert_eq!(text, expected);
+    }
+
     #[cfg(feature = "serde")]
     #[rstest]
     fn de_serialize_text_buf_into_and_from_tokens_eq(
@@ -435,11 +1149,74 @@ mod tests {
 
     #[cfg(feature = "serde")]
     #[rstest]
-    #[case::empty(serde::harness::borrowed_str_token(""))]
-    #[case::non_empty(serde::harness::borrowed_str_token("\u{FEFF}"))]
+    #[case::empty(
+        serde::harness::borrowed_str_token(""),
+        "failed to construct text: no legible content: empty"
+    )]
+    #[case::non_empty(
+        serde::harness::borrowed_str_token("\u{FEFF}"),
+        "failed to construct text: no legible content: only default-ignorable code points at bytes 0..3"
+    )]
     fn deserialize_text_buf_from_illegible_tokens_then_illegible_error(
         #[case] tokens: impl Iterator<Item = Token>,
+        #[case] expected: &str,
+    ) {
+        serde::harness::assert_deserialize_error_eq::<TextBuf, Vec<_>>(tokens, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::private_use(
+        serde::harness::borrowed_str_token("\u{E064}"),
+        "failed to construct text: no legible content: only private-use characters at bytes 0..3"
+    )]
+    fn deserialize_strict_text_buf_from_illegible_tokens_then_illegible_error(
+        #[case] tokens: impl Iterator<Item = Token>,
+        #[case] expected: &str,
+    ) {
+        serde::harness::assert_deserialize_error_eq::<Legible<String, Strict>, Vec<_>>(
+            tokens, expected,
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::spaces(
+        serde::harness::borrowed_str_token("   "),
+        "failed to construct text: no legible content: only whitespace at bytes 0..1"
+    )]
+    #[case::newline(
+        serde::harness::borrowed_str_token("\n"),
+        "failed to construct text: no legible content: only whitespace at bytes 0..1"
+    )]
+    fn deserialize_visible_text_buf_from_blank_tokens_then_illegible_error(
+        #[case] tokens: impl Iterator<Item = Token>,
+        #[case] expected: &str,
+    ) {
+        serde::harness::assert_deserialize_error_eq::<VisibleTextBuf, Vec<_>>(tokens, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn de_serialize_line_buf_into_and_from_tokens_eq(legible: impl Iterator<Item = Token>) {
+        let line = LineBuf::try_from("legible").unwrap();
+        serde::harness::assert_into_and_from_tokens_eq::<_, Vec<_>>(line, legible);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::newline(
+        serde::harness::borrowed_str_token("legible\n"),
+        "failed to construct text: no legible content: line or paragraph separator at bytes 7..8"
+    )]
+    #[case::line_separator(
+        serde::harness::borrowed_str_token("\u{2028}"),
+        "failed to construct text: no legible content: line or paragraph separator at bytes 0..3"
+    )]
+    fn deserialize_line_buf_from_multi_line_tokens_then_illegible_error(
+        #[case] tokens: impl Iterator<Item = Token>,
+        #[case] expected: &str,
     ) {
-        serde::harness::assert_deserialize_error_eq_illegible_error::<TextBuf, Vec<_>>(tokens);
+        serde::harness::assert_deserialize_error_eq::<LineBuf, Vec<_>>(tokens, expected);
     }
 }
@@ -0,0 +1,451 @@
+//! Caseless comparison of legible text.
+//!
+//! Case folding is provided by the [`caseless`] crate, which requires `std`. That crate also
+//! enables the `std` feature of the [`unicode-normalization`] crate.
+//!
+//! [`caseless`]: https://crates.io/crates/caseless
+//! [`unicode-normalization`]: https://crates.io/crates/unicode-normalization
+
+#![cfg(feature = "caseless")]
+#![cfg_attr(docsrs, doc(cfg(feature = "caseless")))]
+
+#[cfg(feature = "serde")]
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use alloc::borrow::{Borrow, ToOwned};
+use alloc::string::String;
+use caseless::Caseless as _;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::str::Chars;
+use unicode_normalization::{Decompositions, UnicodeNormalization as _};
+
+use crate::policy::{LegibilityPolicy, Standard};
+use crate::Legible;
+
+pub type CaselessText = Caseless<str>;
+
+pub type CaselessTextBuf = Caseless<String>;
+
+/// Legible text that is compared and hashed without regard to case or composition.
+///
+/// Comparisons use canonical caseless matching per the Unicode Standard (section 3.13): text is
+/// decomposed into NFD, folded with default case folding, and decomposed into NFD again. For
+/// example, `Straße` and `STRASSE` are equal, as are composed and decomposed forms of `é`. The
+/// text itself is not modified and is displayed and serialized as is.
+///
+/// The ordering of `Caseless` text is consistent with equality, but is not a collation.
+#[repr(transparent)]
+pub struct Caseless<T, P = Standard>
+where
+    T: ?Sized,
+{
+    text: Legible<T, P>,
+}
+
+impl<P> Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    const fn from_text(text: &Legible<str, P>) -> &Self {
+        // SAFETY: `Caseless` is `repr(transparent)`: `Legible` and `Caseless` have the same
+        //         representation.
+        unsafe { &*(text as *const Legible<str, P> as *const Caseless<str, P>) }
+    }
+
+    pub fn to_caseless_text_buf(&self) -> Caseless<String, P> {
+        Caseless {
+            text: self.text.to_text_buf(),
+        }
+    }
+
+    pub fn as_text(&self) -> &Legible<str, P> {
+        &self.text
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.text.as_str()
+    }
+
+    fn folded(&self) -> Decompositions<caseless::CaseFold<Decompositions<Chars<'_>>>> {
+        fold(self.as_str())
+    }
+}
+
+impl<P> Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    pub fn into_text_buf(self) -> Legible<String, P> {
+        self.text
+    }
+
+    pub fn as_text_buf(&self) -> &Legible<String, P> {
+        &self.text
+    }
+
+    pub fn as_caseless_text(&self) -> &Caseless<str, P> {
+        Caseless::from_text(self.text.as_text())
+    }
+}
+
+impl<P> AsRef<str> for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<P> AsRef<str> for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<P> AsRef<Legible<str, P>> for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &Legible<str, P> {
+        self.as_text()
+    }
+}
+
+impl<P> AsRef<Legible<str, P>> for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &Legible<str, P> {
+        self.as_text()
+    }
+}
+
+impl<P> Borrow<Caseless<str, P>> for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn borrow(&self) -> &Caseless<str, P> {
+        self.as_caseless_text()
+    }
+}
+
+impl<P> Clone for Caseless<String, P> {
+    fn clone(&self) -> Self {
+        Caseless {
+            text: self.text.clone(),
+        }
+    }
+}
+
+impl<P> Debug for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("Caseless")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl<P> Debug for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_caseless_text(), formatter)
+    }
+}
+
+impl<P> Deref for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    type Target = Legible<str, P>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_text()
+    }
+}
+
+impl<P> Deref for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    type Target = Caseless<str, P>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_caseless_text()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P> Deserialize<'de> for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+    Legible<String, P>: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Legible::<String, P>::deserialize(deserializer).map(Caseless::from)
+    }
+}
+
+impl<P> Display for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.as_str())
+    }
+}
+
+impl<P> Display for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.as_str())
+    }
+}
+
+impl<P> Eq for Caseless<str, P> where P: LegibilityPolicy {}
+
+impl<P> Eq for Caseless<String, P> where P: LegibilityPolicy {}
+
+impl<P> From<Legible<String, P>> for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: Legible<String, P>) -> Self {
+        Caseless { text }
+    }
+}
+
+impl<P> From<Caseless<String, P>> for Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: Caseless<String, P>) -> Self {
+        text.into_text_buf()
+    }
+}
+
+impl<'a, P> From<&'a Legible<str, P>> for &'a Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: &'a Legible<str, P>) -> Self {
+        Caseless::from_text(text)
+    }
+}
+
+impl<P> Hash for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        for point in self.folded() {
+            point.hash(state);
+        }
+        // Like `str`, write a terminator so that the hashes of sequences of text differ.
+        state.write_u8(0xFF);
+    }
+}
+
+impl<P> Hash for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_caseless_text().hash(state)
+    }
+}
+
+impl<P> Ord for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.folded().cmp(other.folded())
+    }
+}
+
+impl<P> Ord for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_caseless_text().cmp(other.as_caseless_text())
+    }
+}
+
+impl<P> PartialEq for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.folded().eq(other.folded())
+    }
+}
+
+impl<P> PartialEq for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_caseless_text().eq(other.as_caseless_text())
+    }
+}
+
+impl<P> PartialOrd for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P> PartialOrd for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P> Serialize for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P> Serialize for Caseless<String, P>
+where
+    P: LegibilityPolicy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_caseless_text().serialize(serializer)
+    }
+}
+
+impl<P> ToOwned for Caseless<str, P>
+where
+    P: LegibilityPolicy,
+{
+    type Owned = Caseless<String, P>;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_caseless_text_buf()
+    }
+}
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Returns `true` if the text and the given string slice match without regard to case or
+    /// composition.
+    ///
+    /// See [`Caseless`].
+    pub fn eq_caseless(&self, other: impl AsRef<str>) -> bool {
+        fold(self.as_str()).eq(fold(other.as_ref()))
+    }
+
+    pub fn as_caseless(&self) -> &Caseless<str, P> {
+        Caseless::from_text(self)
+    }
+}
+
+impl<P> Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    pub fn into_caseless(self) -> Caseless<String, P> {
+        Caseless::from(self)
+    }
+}
+
+// Folds text for canonical caseless matching: `NFD(toCasefold(NFD(text)))`.
+fn fold(text: &str) -> Decompositions<caseless::CaseFold<Decompositions<Chars<'_>>>> {
+    text.chars().nfd().default_case_fold().nfd()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+    #[cfg(feature = "serde")]
+    use serde_test::Token;
+    use std::collections::{BTreeSet, HashSet};
+
+    use crate::text::{CaselessTextBuf, Text, TextBuf};
+
+    #[rstest]
+    #[case::ascii("legible", "LeGiBlE")]
+    #[case::sharp_s("Stra\u{DF}e", "STRASSE")]
+    #[case::composition("caf\u{E9}", "CAFE\u{301}")]
+    #[case::greek("\u{1FC3}", "\u{397}\u{345}")]
+    fn eq_caseless_text_then_true(#[case] text: &str, #[case] other: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        let other = Text::try_from_str(other).unwrap();
+        assert!(text.eq_caseless(other));
+        assert!(other.eq_caseless(text));
+        assert_eq!(text.as_caseless(), other.as_caseless());
+    }
+
+    #[rstest]
+    #[case::ascii("legible", "illegible")]
+    #[case::compatibility("\u{FF21}", "a")]
+    fn eq_caseless_text_then_false(#[case] text: &str, #[case] other: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        assert!(!text.eq_caseless(other));
+    }
+
+    #[rstest]
+    fn collect_caseless_text_bufs_then_keys_deduplicated() {
+        let texts = ["Stra\u{DF}e", "STRASSE", "strasse", "Strasse"]
+            .map(|text| TextBuf::try_from(text).unwrap().into_caseless());
+        let hashed: HashSet<CaselessTextBuf> = texts.iter().cloned().collect();
+        let ordered: BTreeSet<CaselessTextBuf> = texts.iter().cloned().collect();
+        assert_eq!(hashed.len(), 1);
+        assert_eq!(ordered.len(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn de_serialize_caseless_text_buf_then_text_unmodified() {
+        let text = TextBuf::try_from("Stra\u{DF}e").unwrap().into_caseless();
+        serde_test::assert_tokens(&text, &[Token::Str("Stra\u{DF}e")]);
+        serde_test::assert_de_tokens(&text, &[Token::Str("STRASSE")]);
+    }
+}
@@ -0,0 +1,49 @@
+//! Display widths in columns.
+
+#[cfg(feature = "alloc")]
+use crate::grapheme::Grapheme;
+use crate::width::WidthContext;
+
+/// A display width in columns measured in a [`WidthContext`].
+///
+/// Width-aware APIs accept either `Columns` or a `usize`, which is a display width in the
+/// [`WidthContext::Narrow`] context.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Columns {
+    width: usize,
+    context: WidthContext,
+}
+
+impl Columns {
+    pub const fn new(width: usize, context: WidthContext) -> Self {
+        Columns { width, context }
+    }
+
+    /// Constructs a display width in the [`WidthContext::Cjk`] context.
+    pub const fn cjk(width: usize) -> Self {
+        Columns::new(width, WidthContext::Cjk)
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn context(&self) -> WidthContext {
+        self.context
+    }
+
+    pub(crate) fn measure(&self, text: &str) -> usize {
+        self.context.width(text)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn measure_grapheme(&self, grapheme: &Grapheme) -> usize {
+        grapheme.width_in(self.context)
+    }
+}
+
+impl From<usize> for Columns {
+    fn from(width: usize) -> Self {
+        Columns::new(width, WidthContext::Narrow)
+    }
+}
@@ -0,0 +1,98 @@
+//! Confusable detection of legible text.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::policy::LegibilityPolicy;
+use crate::security::RestrictionLevel;
+#[cfg(feature = "alloc")]
+use crate::IllegibleError;
+use crate::{Legible, StrExt as _};
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Computes the confusable skeleton of the text per UTS39.
+    ///
+    /// See [`StrExt::to_skeleton`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the skeleton is not legible per the policy `P`.
+    ///
+    /// [`StrExt::to_skeleton`]: crate::StrExt::to_skeleton
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn skeleton(&self) -> Result<Legible<String, P>, IllegibleError<String>> {
+        Legible::try_from(self.as_str().to_skeleton())
+    }
+
+    /// Returns `true` if the text and the given string slice are visually confusable per UTS39.
+    ///
+    /// Text and a string slice are confusable if they have the same confusable skeleton, such as
+    /// `"paypal"` and `"p\u{430}ypa1"`. Note that text is confusable with itself.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn is_confusable_with(&self, other: impl AsRef<str>) -> bool {
+        self.as_str().to_skeleton() == other.as_ref().to_skeleton()
+    }
+
+    /// Returns `true` if the text satisfies the given restriction level per UTS39.
+    ///
+    /// See [`StrExt::restriction_level`].
+    ///
+    /// [`StrExt::restriction_level`]: crate::StrExt::restriction_level
+    pub fn is_restricted_to(&self, level: RestrictionLevel) -> bool {
+        self.restriction_level() <= level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+
+    use crate::security::RestrictionLevel;
+    use crate::text::Text;
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::cyrillic("paypal", "p\u{430}ypal")]
+    #[case::digit_and_cyrillic("paypal", "p\u{430}ypa1")]
+    #[case::zero_width_space("paypal", "pay\u{200B}pal")]
+    #[case::same("legible", "legible")]
+    fn text_is_confusable_with_str_then_true(#[case] text: &str, #[case] other: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        assert!(text.is_confusable_with(other));
+        assert_eq!(
+            text.skeleton().unwrap(),
+            Text::try_from_str(other).unwrap().skeleton().unwrap(),
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::different("paypal", "legible")]
+    #[case::case("paypal", "PAYPAL")]
+    fn text_is_confusable_with_str_then_false(#[case] text: &str, #[case] other: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        assert!(!text.is_confusable_with(other));
+    }
+
+    #[rstest]
+    #[case::ascii("paypal", RestrictionLevel::AsciiOnly, true)]
+    #[case::single_script("Ελληνικά", RestrictionLevel::AsciiOnly, false)]
+    #[case::single_script("Ελληνικά", RestrictionLevel::SingleScript, true)]
+    #[case::mixed_script("p\u{430}ypal", RestrictionLevel::ModeratelyRestrictive, false)]
+    #[case::mixed_script("p\u{430}ypal", RestrictionLevel::MinimallyRestrictive, true)]
+    fn text_is_restricted_to_level_then_eq(
+        #[case] text: &str,
+        #[case] level: RestrictionLevel,
+        #[case] expected: bool,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(text.is_restricted_to(level), expected);
+    }
+}
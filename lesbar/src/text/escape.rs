@@ -0,0 +1,204 @@
+//! Visible escaping of illegible text.
+
+use core::fmt::{self, Debug, Display, Formatter, Write as _};
+
+use crate::grapheme::Grapheme;
+use crate::policy::LegibilityPolicy;
+use crate::{Legible, StrExt as _};
+
+/// A [`Display`] adapter that escapes illegible grapheme clusters in text.
+///
+/// Grapheme clusters that are legible per the policy `P` are displayed as is, except for their
+/// default-ignorable code points (such as zero-width joiners and variation selectors), which are
+/// escaped. The code points of illegible grapheme clusters are displayed as their Control Pictures
+/// (such as `␀` for U+0000) if they are C0 control characters or delete and as `\u{...}` escapes
+/// otherwise. For example, `"a\u{200B}b"` is displayed as `a\u{200b}b` and `"a\u{200C}b"` is
+/// displayed as `a\u{200c}b`.
+///
+/// See [`Legible::escape_invisible`].
+pub struct EscapeInvisible<'t, P> {
+    text: &'t Legible<str, P>,
+}
+
+impl<P> Clone for EscapeInvisible<'_, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for EscapeInvisible<'_, P> {}
+
+impl<P> Debug for EscapeInvisible<'_, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("EscapeInvisible")
+            .field("text", &self.text.as_str())
+            .finish()
+    }
+}
+
+impl<P> Display for EscapeInvisible<'_, P>
+where
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        for grapheme in self.text.graphemes() {
+            if P::is_legible_grapheme(grapheme) {
+                let text = grapheme.as_str();
+                let mut start = 0;
+                for (index, point) in grapheme.default_ignorable_indices() {
+                    formatter.write_str(&text[start..index])?;
+                    write!(formatter, "{}", point.escape_unicode())?;
+                    start = index + point.len_utf8();
+                }
+                formatter.write_str(&text[start..])?;
+            }
+            else {
+                write_escaped(formatter, grapheme)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Gets a [`Display`] adapter that escapes grapheme clusters in the text that are not legible
+    /// per the policy `P` and default-ignorable code points in legible grapheme clusters.
+    ///
+    /// The alternate [`Debug`] format (`{:#?}`) of text also escapes illegible grapheme clusters in
+    /// this way. See [`EscapeInvisible`].
+    pub fn escape_invisible(&self) -> EscapeInvisible<'_, P> {
+        EscapeInvisible { text: self }
+    }
+
+    // Writes the text as a quoted string like the `Debug` format of `str`, but with illegible
+    // grapheme clusters escaped visibly. The code points of legible grapheme clusters are escaped
+    // like the `Debug` format of `str`, so control characters like `\n` and `\u{1b}` are never
+    // written as is. See `EscapeInvisible`.
+    pub(crate) fn fmt_escaped_debug(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_char('"')?;
+        for grapheme in self.graphemes() {
+            if P::is_legible_grapheme(grapheme) {
+                let mut ignorable = grapheme.default_ignorable_indices().peekable();
+                for (index, point) in grapheme.as_str().char_indices() {
+                    match point {
+                        _ if ignorable.next_if(|&(start, _)| start == index).is_some() => {
+                            write!(formatter, "{}", point.escape_unicode())?
+                        },
+                        '\'' => formatter.write_char(point)?,
+                        _ => write!(formatter, "{}", point.escape_debug())?,
+                    }
+                }
+            }
+            else {
+                write_escaped(formatter, grapheme)?;
+            }
+        }
+        formatter.write_char('"')
+    }
+}
+
+fn write_escaped(formatter: &mut Formatter<'_>, grapheme: &Grapheme) -> fmt::Result {
+    for point in grapheme.as_str().chars() {
+        match point {
+            '\u{0}'..='\u{1F}' => formatter.write_char(
+                char::from_u32(0x2400 + u32::from(point)).expect("invalid control picture"),
+            )?,
+            '\u{7F}' => formatter.write_char('\u{2421}')?,
+            _ => write!(formatter, "{}", point.escape_unicode())?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+    use std::format;
+
+    use crate::grapheme::Grapheme;
+    use crate::policy::LegibilityPolicy;
+    use crate::text::{Text, VisibleText};
+    use crate::Legible;
+
+    // A policy for which control characters other than whitespace are illegible.
+    struct NoControl;
+
+    impl LegibilityPolicy for NoControl {
+        fn is_legible_grapheme(grapheme: &Grapheme) -> bool {
+            grapheme.is_legible_text()
+                && grapheme
+                    .as_str()
+                    .chars()
+                    .all(|point| !point.is_control() || point.is_whitespace())
+        }
+    }
+
+    #[rstest]
+    #[case::legible("legible", "legible")]
+    #[case::zero_width_space("a\u{200B}b", "a\\u{200b}b")]
+    #[case::bidi_marks("\u{200E}legible\u{200F}", "\\u{200e}legible\\u{200f}")]
+    #[case::zero_width_non_joiner("a\u{200C}b", "a\\u{200c}b")]
+    #[case::emoji_zwj_sequence(
+        "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+        "\u{1F3F3}\\u{fe0f}\\u{200d}\u{1F308}"
+    )]
+    #[case::tag_sequence(
+        "\u{1F3F4}\u{E0067}\u{E0062}\u{E007F}",
+        "\u{1F3F4}\\u{e0067}\\u{e0062}\\u{e007f}"
+    )]
+    fn escape_invisible_in_text_then_display_eq(#[case] text: &str, #[case] expected: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(format!("{}", text.escape_invisible()), expected);
+    }
+
+    #[rstest]
+    #[case::control("a\u{0}\u{1B}\u{7F}", "a␀␛␡")]
+    #[case::zero_width_space_and_control("a\u{200B}\u{0}", "a\\u{200b}␀")]
+    fn escape_invisible_in_text_with_illegible_controls_then_display_eq(
+        #[case] text: &str,
+        #[case] expected: &str,
+    ) {
+        let text = Legible::<str, NoControl>::try_from_str(text).unwrap();
+        assert_eq!(format!("{}", text.escape_invisible()), expected);
+    }
+
+    #[rstest]
+    fn escape_invisible_in_visible_text_then_whitespace_escaped() {
+        let text = VisibleText::try_from_str(" legible\t").unwrap();
+        assert_eq!(format!("{}", text.escape_invisible()), "\\u{20}legible␉");
+    }
+
+    #[rstest]
+    #[case::legible("legible", "\"legible\"")]
+    #[case::zero_width_space("a\u{200B}b", "\"a\\u{200b}b\"")]
+    #[case::quotes("\"a\\b\"", "\"\\\"a\\\\b\\\"\"")]
+    #[case::wide("練習", "\"練習\"")]
+    #[case::variation_selector("\u{2764}\u{FE0F}", "\"\u{2764}\\u{fe0f}\"")]
+    fn debug_text_with_alternate_flag_then_escaped(#[case] text: &str, #[case] expected: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(format!("{:#?}", text), expected);
+    }
+
+    #[rstest]
+    fn debug_text_with_control_characters_and_alternate_flag_then_escaped() {
+        let text = Text::try_from_str("a\nb\t\u{1B}").unwrap();
+        let debug = format!("{:#?}", text);
+        assert!(!debug.chars().any(char::is_control));
+        assert_eq!(debug, format!("{:?}", "a\nb\t\u{1B}"));
+    }
+
+    #[rstest]
+    fn debug_text_then_str_debug_eq() {
+        let text = Text::try_from_str("a\"b").unwrap();
+        assert_eq!(format!("{:?}", text), format!("{:?}", "a\"b"));
+    }
+}
@@ -1,79 +1,326 @@
 //! Non-empty [string][prim@str] types that represent legible text.
 
+mod align;
+mod bidi;
+mod bounded;
 mod buf;
+mod caseless;
+mod columns;
+mod confusable;
+mod escape;
+mod normal;
+mod precis;
+mod truncate;
+mod wrap;
 
-#[cfg(feature = "alloc")]
-use alloc::borrow::ToOwned;
 use core::fmt::{self, Debug, Display, Formatter};
-use core::iter::Peekable;
-use core::mem;
-use core::ops::{Deref, DerefMut};
+use core::iter::{FusedIterator, Peekable};
+use core::marker::PhantomData;
+use core::num::NonZeroUsize;
+use core::ops::{Deref, DerefMut, Range};
 use mitsein::iter1::Iterator1;
 use mitsein::str1::Str1;
+use unicode_width::UnicodeWidthStr;
+#[cfg(feature = "alloc")]
+use {alloc::borrow::Cow, alloc::borrow::ToOwned, alloc::boxed::Box, alloc::string::String};
 
-use crate::iter::{GraphemeIndices, Graphemes};
-use crate::{IllegibleError, Legible, StrExt as _};
+use crate::iter::{GraphemeIndices, Graphemes, SplitLines};
+use crate::policy::{LegibilityPolicy, NonBlank, NonZeroWidth, SingleLine, Standard};
+use crate::width::WidthContext;
+use crate::{diagnose, IllegibleError, Legible, StrExt as _, Visible};
 
+pub use crate::text::align::*;
+pub use crate::text::bidi::*;
+#[cfg(feature = "alloc")]
+pub use crate::text::bounded::*;
 #[cfg(feature = "alloc")]
 pub use crate::text::buf::*;
+#[cfg(feature = "caseless")]
+pub use crate::text::caseless::*;
+pub use crate::text::columns::*;
+pub use crate::text::escape::*;
+#[cfg(feature = "alloc")]
+pub use crate::text::normal::*;
+#[cfg(feature = "precis")]
+pub use crate::text::precis::*;
+#[cfg(feature = "alloc")]
+pub use crate::text::truncate::*;
+#[cfg(feature = "alloc")]
+pub use crate::text::wrap::*;
 
 pub type Text = Legible<str>;
 
-impl Text {
+pub type VisibleText = Visible<str>;
+
+/// Legible text that has no line breaks.
+///
+/// See [`SingleLine`].
+pub type Line = Legible<str, SingleLine>;
+
+/// An iterator over the legible lines of text.
+///
+/// See [`Legible::legible_lines`].
+pub struct Lines<'t, P = Standard> {
+    input: SplitLines<'t>,
+    policy: PhantomData<fn() -> P>,
+}
+
+impl<P> Clone for Lines<'_, P> {
+    fn clone(&self) -> Self {
+        Lines {
+            input: self.input.clone(),
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<P> Debug for Lines<'_, P> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Lines")
+            .field("input", &self.input)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'t, P> FusedIterator for Lines<'t, P> where P: 't + LegibilityPolicy {}
+
+impl<'t, P> Iterator for Lines<'t, P>
+where
+    P: 't + LegibilityPolicy,
+{
+    type Item = &'t Legible<str, SingleLine<P>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input
+            .by_ref()
+            .find_map(|line| Legible::try_from_str(line).ok())
+    }
+}
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     pub const fn from_str1_unchecked(text: &Str1) -> &Self {
-        // SAFETY: `Legible` is `repr(transparent)`: `Str1` and `Text` have the same
-        //         representation.
-        unsafe { mem::transmute::<&'_ Str1, &'_ Text>(text) }
+        // SAFETY: `Legible` is `repr(transparent)` and its policy is zero-sized: `Str1` and `Text`
+        //         have the same representation.
+        unsafe { &*(text as *const Str1 as *const Legible<str, P>) }
     }
 
     pub const fn from_mut_str1_unchecked(text: &mut Str1) -> &mut Self {
-        // SAFETY: `Legible` is `repr(transparent)`: `Str1` and `Text` have the same
-        //         representation.
-        unsafe { mem::transmute::<&'_ mut Str1, &'_ mut Text>(text) }
+        // SAFETY: `Legible` is `repr(transparent)` and its policy is zero-sized: `Str1` and `Text`
+        //         have the same representation.
+        unsafe { &mut *(text as *mut Str1 as *mut Legible<str, P>) }
     }
 
     pub fn try_from_str(text: &str) -> Result<&Self, IllegibleError<&str>> {
         Str1::try_from_str(text)
-            .map_err(IllegibleError::from_illegible)
-            .and_then(|text1| Text::try_from_str1(text1).map_err(|error| error.map(Str1::as_str)))
+            .map_err(IllegibleError::from_empty)
+            .and_then(|text1| {
+                Legible::try_from_str1(text1).map_err(|error| error.map(Str1::as_str))
+            })
     }
 
     pub fn try_from_mut_str(text: &mut str) -> Result<&mut Self, IllegibleError<&mut str>> {
         Str1::try_from_mut_str(text)
-            .map_err(IllegibleError::from_illegible)
+            .map_err(IllegibleError::from_empty)
             .and_then(|text| {
-                Text::try_from_mut_str1(text).map_err(|error| error.map(Str1::as_mut_str))
+                Legible::try_from_mut_str1(text).map_err(|error| error.map(Str1::as_mut_str))
             })
     }
 
     pub fn try_from_str1(text: &Str1) -> Result<&Self, IllegibleError<&Str1>> {
-        if text.has_legible_text() {
-            Ok(Text::from_str1_unchecked(text))
+        if text.has_legible_text_with::<P>() {
+            Ok(Legible::from_str1_unchecked(text))
         }
         else {
-            Err(IllegibleError::from_illegible(text))
+            let diagnosis = diagnose::<P>(text);
+            Err(IllegibleError::from_illegible(text, diagnosis))
         }
     }
 
     pub fn try_from_mut_str1(text: &mut Str1) -> Result<&mut Self, IllegibleError<&mut Str1>> {
-        if text.has_legible_text() {
-            Ok(Text::from_mut_str1_unchecked(text))
+        if text.has_legible_text_with::<P>() {
+            Ok(Legible::from_mut_str1_unchecked(text))
         }
         else {
-            Err(IllegibleError::from_illegible(text))
+            let diagnosis = diagnose::<P>(text);
+            Err(IllegibleError::from_illegible(text, diagnosis))
         }
     }
 
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-    pub fn into_text_buf(self: BoxedText) -> TextBuf {
-        TextBuf::from(self)
+    pub fn into_text_buf(self: Box<Legible<str, P>>) -> Legible<String, P> {
+        Legible::from(self)
     }
 
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-    pub fn to_text_buf(&self) -> TextBuf {
-        TextBuf::from(self)
+    pub fn to_text_buf(&self) -> Legible<String, P> {
+        Legible::from(self)
+    }
+
+    /// Removes invisible code points from the text.
+    ///
+    /// The text is borrowed if it has no invisible code points. See [`StrExt::strip_invisible`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stripped text is illegible per the policy `P`.
+    ///
+    /// [`StrExt::strip_invisible`]: crate::StrExt::strip_invisible
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn strip_invisible(&self) -> Result<Cow<'_, Legible<str, P>>, IllegibleError<String>> {
+        match self.as_str().strip_invisible() {
+            Cow::Owned(text) => Legible::try_from(text).map(Cow::Owned),
+            _ => Ok(Cow::Borrowed(self)),
+        }
+    }
+
+    /// Removes excess combining marks from the grapheme clusters of the text.
+    ///
+    /// The text is borrowed if no grapheme cluster has more than `n` combining marks. See
+    /// [`StrExt::limit_combining_marks`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the limited text is illegible per the policy `P`.
+    ///
+    /// [`StrExt::limit_combining_marks`]: crate::StrExt::limit_combining_marks
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn limit_combining_marks(
+        &self,
+        n: usize,
+    ) -> Result<Cow<'_, Legible<str, P>>, IllegibleError<String>> {
+        match self.as_str().limit_combining_marks(n) {
+            Cow::Owned(text) => Legible::try_from(text).map(Cow::Owned),
+            _ => Ok(Cow::Borrowed(self)),
+        }
+    }
+
+    /// Removes hidden zero-width code points from the text.
+    ///
+    /// The text is borrowed if it has no hidden zero-width code points. See
+    /// [`StrExt::strip_zero_width`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stripped text is illegible per the policy `P`.
+    ///
+    /// [`StrExt::strip_zero_width`]: crate::StrExt::strip_zero_width
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn strip_zero_width(&self) -> Result<Cow<'_, Legible<str, P>>, IllegibleError<String>> {
+        match self.as_str().strip_zero_width() {
+            Cow::Owned(text) => Legible::try_from(text).map(Cow::Owned),
+            _ => Ok(Cow::Borrowed(self)),
+        }
+    }
+
+    /// Removes grapheme clusters that are not legible per the policy `P` from the start and end of
+    /// the text.
+    ///
+    /// Text always has a legible grapheme cluster, so the trimmed text is legible.
+    pub fn trim_illegible(&self) -> &Self {
+        self.trim_start_illegible().trim_end_illegible()
+    }
+
+    /// Removes grapheme clusters that are not legible per the policy `P` from the start of the
+    /// text.
+    pub fn trim_start_illegible(&self) -> &Self {
+        let start = self
+            .grapheme_indices()
+            .find(|(_, grapheme)| P::is_legible_grapheme(grapheme))
+            .map(|(index, _)| index)
+            .expect("text has no legible grapheme clusters");
+        self.slice_legible_unchecked(start..self.len().get())
+    }
+
+    /// Removes grapheme clusters that are not legible per the policy `P` from the end of the text.
+    pub fn trim_end_illegible(&self) -> &Self {
+        let end = self
+            .grapheme_indices()
+            .rev()
+            .find(|(_, grapheme)| P::is_legible_grapheme(grapheme))
+            .map(|(index, grapheme)| index + grapheme.as_str().len())
+            .expect("text has no legible grapheme clusters");
+        self.slice_legible_unchecked(0..end)
+    }
+
+    /// Removes whitespace grapheme clusters from the start and end of the text.
+    ///
+    /// Returns `None` if the trimmed text is not legible per the policy `P`, such as when the text
+    /// has only whitespace. See [`Grapheme::is_whitespace`].
+    ///
+    /// Whitespace is not legible per [`NonBlank`] policies, so [`trim_illegible`] trims whitespace
+    /// from [`VisibleText`] and cannot fail.
+    ///
+    /// [`Grapheme::is_whitespace`]: crate::grapheme::Grapheme::is_whitespace
+    /// [`trim_illegible`]: crate::Legible::trim_illegible
+    pub fn trim_whitespace(&self) -> Option<&Self> {
+        self.trim_start_whitespace()?.trim_end_whitespace()
+    }
+
+    /// Removes whitespace grapheme clusters from the start of the text.
+    ///
+    /// Returns `None` if the trimmed text is not legible per the policy `P`. See
+    /// [`trim_whitespace`].
+    ///
+    /// [`trim_whitespace`]: crate::Legible::trim_whitespace
+    pub fn trim_start_whitespace(&self) -> Option<&Self> {
+        let start = self
+            .grapheme_indices()
+            .find(|(_, grapheme)| !grapheme.is_whitespace())
+            .map_or(self.len().get(), |(index, _)| index);
+        Legible::try_from_str(&self.as_str()[start..]).ok()
+    }
+
+    /// Removes whitespace grapheme clusters from the end of the text.
+    ///
+    /// Returns `None` if the trimmed text is not legible per the policy `P`. See
+    /// [`trim_whitespace`].
+    ///
+    /// [`trim_whitespace`]: crate::Legible::trim_whitespace
+    pub fn trim_end_whitespace(&self) -> Option<&Self> {
+        let end = self
+            .grapheme_indices()
+            .rev()
+            .find(|(_, grapheme)| !grapheme.is_whitespace())
+            .map_or(0, |(index, grapheme)| index + grapheme.as_str().len());
+        Legible::try_from_str(&self.as_str()[..end]).ok()
+    }
+
+    /// Gets an iterator over the legible lines of the text.
+    ///
+    /// Lines are separated by line breaks (see [`StrExt::split_lines`]) and lines that are not
+    /// legible per the policy `P` are skipped. Note that the iterator may be empty, because text
+    /// that has only line breaks (such as `"\n"`) is legible per some policies but has no legible
+    /// lines. Use [`str::lines`] (via [`Legible::as_str`]) to get all of the lines instead.
+    ///
+    /// [`StrExt::split_lines`]: crate::StrExt::split_lines
+    pub fn legible_lines(&self) -> Lines<'_, P> {
+        Lines {
+            input: self.as_str().split_lines(),
+            policy: PhantomData,
+        }
+    }
+
+    pub fn grapheme_count(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.graphemes().count()).expect("text has no grapheme clusters")
+    }
+
+    /// Gets the number of grapheme clusters in the text that are legible per the policy `P`.
+    pub fn legible_grapheme_count(&self) -> NonZeroUsize {
+        NonZeroUsize::new(
+            self.graphemes()
+                .filter(|grapheme| P::is_legible_grapheme(grapheme))
+                .count(),
+        )
+        .expect("text has no legible grapheme clusters")
     }
 
     pub fn graphemes1(&self) -> Iterator1<Peekable<Graphemes<'_>>> {
@@ -84,6 +331,13 @@ impl Text {
         Iterator1::try_from_iter(self.grapheme_indices()).expect("text has no grapheme clusters")
     }
 
+    // Slices the text between grapheme cluster boundaries. The slice must contain a legible
+    // grapheme cluster, and so is legible per the policy `P`.
+    fn slice_legible_unchecked(&self, range: Range<usize>) -> &Self {
+        // SAFETY: The slice contains a legible grapheme cluster, so it is non-empty.
+        Legible::from_str1_unchecked(unsafe { Str1::from_str_unchecked(&self.as_str()[range]) })
+    }
+
     pub const fn as_str1(&self) -> &Str1 {
         &self.text
     }
@@ -101,37 +355,86 @@ impl Text {
     }
 }
 
-impl AsMut<str> for Text {
+impl<P> Legible<str, P>
+where
+    P: NonZeroWidth,
+{
+    /// Gets the display width of the text in columns per UCS and UAX11.
+    pub fn width(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.as_str().width()).expect("text has no display width")
+    }
+
+    /// Gets the display width of the text in columns per UCS and UAX11 in a CJK context.
+    ///
+    /// See [`WidthContext::Cjk`].
+    pub fn width_cjk(&self) -> NonZeroUsize {
+        self.width_in(WidthContext::Cjk)
+    }
+
+    /// Gets the display width of the text in columns per UCS and UAX11 in the given context.
+    pub fn width_in(&self, context: WidthContext) -> NonZeroUsize {
+        NonZeroUsize::new(context.width(self.as_str())).expect("text has no display width")
+    }
+}
+
+impl<P> AsMut<str> for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     fn as_mut(&mut self) -> &mut str {
         self.as_mut_str()
     }
 }
 
-impl AsMut<Str1> for Text {
+impl<P> AsMut<Str1> for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     fn as_mut(&mut self) -> &mut Str1 {
         self.as_mut_str1()
     }
 }
 
-impl AsRef<str> for Text {
+impl<P> AsRef<str> for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     fn as_ref(&self) -> &str {
         self.as_str()
     }
 }
 
-impl AsRef<Str1> for Text {
+impl<P> AsRef<Str1> for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     fn as_ref(&self) -> &Str1 {
         self.as_str1()
     }
 }
 
-impl Debug for Text {
+impl<P> Debug for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Formats the text like a [`str`].
+    ///
+    /// With the alternate flag (`{:#?}`), grapheme clusters that are not legible per the policy
+    /// `P` are escaped. See [`Legible::escape_invisible`].
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
-        write!(formatter, "{:?}", self.as_str())
+        if formatter.alternate() {
+            self.fmt_escaped_debug(formatter)
+        }
+        else {
+            write!(formatter, "{:?}", self.as_str())
+        }
     }
 }
 
-impl Deref for Text {
+impl<P> Deref for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     type Target = Str1;
 
     fn deref(&self) -> &Self::Target {
@@ -139,45 +442,81 @@ impl Deref for Text {
     }
 }
 
-impl DerefMut for Text {
+impl<P> DerefMut for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut_str1()
     }
 }
 
-impl Display for Text {
+impl<P> Display for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         write!(formatter, "{}", self.as_str())
     }
 }
 
-impl<'a> From<&'a Text> for &'a str {
-    fn from(text: &'a Text) -> Self {
+impl<'a, P> From<&'a Legible<str, P>> for &'a str
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: &'a Legible<str, P>) -> Self {
         text.as_str()
     }
 }
 
-impl<'a> From<&'a mut Text> for &'a mut str {
-    fn from(text: &'a mut Text) -> Self {
+impl<'a, P> From<&'a mut Legible<str, P>> for &'a mut str
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: &'a mut Legible<str, P>) -> Self {
         text.as_mut_str()
     }
 }
 
-impl<'a> From<&'a Text> for &'a Str1 {
-    fn from(text: &'a Text) -> Self {
+impl<'a, P> From<&'a Legible<str, NonBlank<P>>> for &'a Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: &'a Legible<str, NonBlank<P>>) -> Self {
+        Legible::from_str1_unchecked(text.as_str1())
+    }
+}
+
+impl<'a, P> From<&'a Legible<str, SingleLine<P>>> for &'a Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: &'a Legible<str, SingleLine<P>>) -> Self {
+        Legible::from_str1_unchecked(text.as_str1())
+    }
+}
+
+impl<'a, P> From<&'a Legible<str, P>> for &'a Str1
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: &'a Legible<str, P>) -> Self {
         text.as_str1()
     }
 }
 
-impl<'a> From<&'a mut Text> for &'a mut Str1 {
-    fn from(text: &'a mut Text) -> Self {
+impl<'a, P> From<&'a mut Legible<str, P>> for &'a mut Str1
+where
+    P: LegibilityPolicy,
+{
+    fn from(text: &'a mut Legible<str, P>) -> Self {
         text.as_mut_str1()
     }
 }
 
-impl<T> PartialEq<&'_ T> for Text
+impl<T, P> PartialEq<&'_ T> for Legible<str, P>
 where
-    Text: PartialEq<T>,
+    Legible<str, P>: PartialEq<T>,
     T: ?Sized,
 {
     fn eq(&self, other: &&'_ T) -> bool {
@@ -185,13 +524,19 @@ where
     }
 }
 
-impl PartialEq<str> for Text {
+impl<P> PartialEq<str> for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     fn eq(&self, other: &str) -> bool {
         self.as_str().eq(other)
     }
 }
 
-impl PartialEq<Str1> for Text {
+impl<P> PartialEq<Str1> for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     fn eq(&self, other: &Str1) -> bool {
         self.as_str1().eq(other)
     }
@@ -199,45 +544,334 @@ impl PartialEq<Str1> for Text {
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-impl ToOwned for Text {
-    type Owned = TextBuf;
+impl<P> ToOwned for Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    type Owned = Legible<String, P>;
 
     fn to_owned(&self) -> Self::Owned {
-        TextBuf::from(self)
+        Legible::from(self)
     }
 }
 
-impl<'a> TryFrom<&'a str> for &'a Text {
+impl<'a, P> TryFrom<&'a str> for &'a Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     type Error = IllegibleError<&'a str>;
 
     fn try_from(text: &'a str) -> Result<Self, Self::Error> {
-        Text::try_from_str(text)
+        Legible::try_from_str(text)
     }
 }
 
-impl<'a> TryFrom<&'a mut str> for &'a mut Text {
+impl<'a, P> TryFrom<&'a mut str> for &'a mut Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     type Error = IllegibleError<&'a mut str>;
 
     fn try_from(text: &'a mut str) -> Result<Self, Self::Error> {
-        Text::try_from_mut_str(text)
+        Legible::try_from_mut_str(text)
     }
 }
 
-impl<'a> TryFrom<&'a Str1> for &'a Text {
+impl<'a, P> TryFrom<&'a Str1> for &'a Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     type Error = IllegibleError<&'a Str1>;
 
     fn try_from(text: &'a Str1) -> Result<Self, Self::Error> {
-        Text::try_from_str1(text)
+        Legible::try_from_str1(text)
     }
 }
 
-impl<'a> TryFrom<&'a mut Str1> for &'a mut Text {
+impl<'a, P> TryFrom<&'a mut Str1> for &'a mut Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
     type Error = IllegibleError<&'a mut Str1>;
 
     fn try_from(text: &'a mut Str1) -> Result<Self, Self::Error> {
-        Text::try_from_mut_str1(text)
+        Legible::try_from_mut_str1(text)
     }
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    extern crate std;
+
+    #[cfg(feature = "alloc")]
+    use alloc::borrow::Cow;
+    use rstest::rstest;
+
+    use crate::diagnosis::Illegibility;
+    use crate::policy::{Lenient, Strict};
+    use crate::text::{Line, Text, VisibleText};
+    use crate::Legible;
+    #[cfg(feature = "alloc")]
+    use {
+        crate::grapheme::Grapheme,
+        crate::policy::{LegibilityPolicy, MaxCombiningMarks},
+    };
+
+    // A policy for which every grapheme cluster is legible, so that any non-empty text is legible.
+    #[cfg(feature = "alloc")]
+    struct Permissive;
+
+    #[cfg(feature = "alloc")]
+    impl LegibilityPolicy for Permissive {
+        fn is_legible_grapheme(_: &Grapheme) -> bool {
+            true
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::non_text_prefix("\u{200B}ZWSP", "ZWSP")]
+    #[case::bidi_override("\u{202E}txt.exe\u{202C}", "txt.exe")]
+    #[case::joiner_outside_emoji(
+        "a\u{200D}\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+        "a\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}"
+    )]
+    fn strip_invisible_from_text_then_stripped_eq(#[case] text: &str, #[case] expected: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        let stripped = text.strip_invisible().unwrap();
+        assert!(matches!(stripped, Cow::Owned(_)));
+        assert_eq!(stripped.as_ref(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    fn strip_invisible_from_text_then_borrowed() {
+        let text = Text::try_from_str("legible").unwrap();
+        assert!(matches!(text.strip_invisible(), Ok(Cow::Borrowed(_))));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    fn strip_invisible_from_invisible_text_then_illegible_error() {
+        let text = Legible::<str, Permissive>::try_from_str("\u{200B}\u{2060}").unwrap();
+        let error = text.strip_invisible().unwrap_err();
+        assert_eq!(error.reason(), Illegibility::Empty);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    fn limit_combining_marks_of_text_then_limited_eq() {
+        let text = Text::try_from_str("Z\u{36B}\u{33F}\u{344}algo").unwrap();
+        let limited = text.limit_combining_marks(1).unwrap();
+        assert!(matches!(limited, Cow::Owned(_)));
+        assert_eq!(limited.as_ref(), "Z\u{36B}algo");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    fn limit_combining_marks_of_only_combining_marks_then_illegible_error() {
+        let text = Legible::<str, MaxCombiningMarks<2, Permissive>>::try_from_str("\u{301}\u{301}")
+            .unwrap();
+        let error = text.limit_combining_marks(0).unwrap_err();
+        assert_eq!(error.reason(), Illegibility::Empty);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::interleaved("wa\u{200B}ter\u{200C}\u{200D}mark", "watermark")]
+    #[case::emoji_zwj_sequence(
+        "\u{2060}\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+        "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}"
+    )]
+    fn strip_zero_width_from_text_then_stripped_eq(#[case] text: &str, #[case] expected: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        let stripped = text.strip_zero_width().unwrap();
+        assert!(matches!(stripped, Cow::Owned(_)));
+        assert_eq!(stripped.as_ref(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    fn strip_zero_width_from_zero_width_text_then_illegible_error() {
+        let text = Legible::<str, Permissive>::try_from_str("\u{200B}\u{200C}").unwrap();
+        let error = text.strip_zero_width().unwrap_err();
+        assert_eq!(error.reason(), Illegibility::Empty);
+    }
+
+    #[rstest]
+    #[case::legible("legible", "legible")]
+    #[case::non_text("\u{FEFF}\u{200B}legible\u{200B}", "legible")]
+    #[case::non_text_within("\u{200B}leg\u{200B}ible\u{200B}", "leg\u{200B}ible")]
+    #[case::whitespace(" legible ", " legible ")]
+    fn trim_illegible_from_text_then_trimmed_eq(#[case] text: &str, #[case] expected: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(text.trim_illegible(), expected);
+    }
+
+    #[rstest]
+    fn trim_start_and_end_illegible_from_text_then_trimmed_eq() {
+        let text = Text::try_from_str("\u{200B}legible\u{2060}").unwrap();
+        assert_eq!(text.trim_start_illegible(), "legible\u{2060}");
+        assert_eq!(text.trim_end_illegible(), "\u{200B}legible");
+    }
+
+    #[rstest]
+    #[case::whitespace(" \tlegible\n", "legible")]
+    #[case::whitespace_within(" leg ible ", "leg ible")]
+    #[case::non_text_and_whitespace("\u{200B} legible", "legible")]
+    fn trim_illegible_from_visible_text_then_trimmed_eq(
+        #[case] text: &str,
+        #[case] expected: &str,
+    ) {
+        let text = VisibleText::try_from_str(text).unwrap();
+        assert_eq!(text.trim_illegible(), expected);
+    }
+
+    #[rstest]
+    #[case::whitespace(" \tlegible\n", Some("legible"))]
+    #[case::ideographic_space("\u{3000}練習\u{3000}", Some("練習"))]
+    #[case::non_text(" \u{200B}legible ", Some("\u{200B}legible"))]
+    #[case::only_whitespace("  ", None)]
+    #[case::only_non_text_after_whitespace(" \u{200B}", None)]
+    fn trim_whitespace_from_text_then_trimmed_eq(
+        #[case] text: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(text.trim_whitespace().map(Text::as_str), expected);
+    }
+
+    #[rstest]
+    fn trim_start_and_end_whitespace_from_text_then_trimmed_eq() {
+        let text = Text::try_from_str(" legible ").unwrap();
+        assert_eq!(text.trim_start_whitespace().unwrap(), "legible ");
+        assert_eq!(text.trim_end_whitespace().unwrap(), " legible");
+    }
+
+    #[rstest]
+    #[case::private_use("\u{E064}")]
+    #[case::private_use_with_non_text("\u{200B}\u{E064}")]
+    fn try_from_private_use_str_with_strict_policy_then_illegible_error(#[case] text: &str) {
+        assert!(Text::try_from_str(text).is_ok());
+        assert!(Legible::<str, Strict>::try_from_str(text).is_err());
+    }
+
+    #[rstest]
+    #[case::acute("\u{301}")]
+    #[case::acute_with_non_text("\u{200B}\u{301}")]
+    fn try_from_combining_str_with_lenient_policy_then_text(#[case] text: &str) {
+        assert!(Text::try_from_str(text).is_err());
+        assert!(Legible::<str, Lenient>::try_from_str(text).is_ok());
+    }
+
+    #[rstest]
+    fn text_macro_with_policy_then_text_eq() {
+        let text: &Legible<str, Strict> = crate::text!(Strict, "legible");
+        assert_eq!(text, "legible");
+    }
+
+    #[rstest]
+    #[case::space(" ")]
+    #[case::spaces_and_tab("  \t")]
+    #[case::ideographic_space("\u{3000}")]
+    #[case::space_and_zwsp(" \u{200B}")]
+    fn try_from_blank_str_then_visible_illegible_error(#[case] text: &str) {
+        assert!(Text::try_from_str(text).is_ok());
+        let error = VisibleText::try_from_str(text).unwrap_err();
+        assert_eq!(error.reason(), Illegibility::Whitespace);
+        assert_eq!(
+            error.span(),
+            Some(0..text.chars().next().unwrap().len_utf8())
+        );
+    }
+
+    #[rstest]
+    #[case::zwsp("\u{200B}", Illegibility::DefaultIgnorable)]
+    #[case::empty("", Illegibility::Empty)]
+    fn try_from_illegible_str_then_visible_illegible_error_reason_eq(
+        #[case] text: &str,
+        #[case] expected: Illegibility,
+    ) {
+        assert_eq!(
+            VisibleText::try_from_str(text).unwrap_err().reason(),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn visible_text_into_text_then_text_eq() {
+        let visible = VisibleText::try_from_str("  legible ").unwrap();
+        let text: &Text = visible.into();
+        assert_eq!(text, "  legible ");
+    }
+
+    #[rstest]
+    fn visible_macro_then_visible_text_eq() {
+        let text: &VisibleText = crate::visible!(" legible");
+        assert_eq!(text, " legible");
+        let text: &Legible<str, crate::policy::NonBlank<Strict>> = crate::visible!(Strict, "x");
+        assert_eq!(text, "x");
+    }
+
+    #[rstest]
+    #[case::newline("legible\n")]
+    #[case::crlf("legible\r\ntext")]
+    #[case::line_separator("legible\u{2028}")]
+    #[case::paragraph_separator("\u{2029}legible")]
+    fn try_from_multi_line_str_then_line_illegible_error(#[case] text: &str) {
+        assert!(Text::try_from_str(text).is_ok());
+        let error = Line::try_from_str(text).unwrap_err();
+        assert_eq!(error.reason(), Illegibility::LineBreak);
+    }
+
+    #[rstest]
+    #[case::one("legible", &["legible"])]
+    #[case::many("a\nb\r\nc\u{2028}d", &["a", "b", "c", "d"])]
+    #[case::illegible_lines("a\n\n\u{200B}\r\nb\n", &["a", "b"])]
+    #[case::illegible_line("a\n\u{200B}\nb", &["a", "b"])]
+    #[case::only_line_breaks("\n\n", &[])]
+    fn legible_lines_of_text_then_lines_eq(#[case] text: &str, #[case] expected: &[&str]) {
+        let text = Text::try_from_str(text).unwrap();
+        let lines: std::vec::Vec<&Line> = text.legible_lines().collect();
+        assert_eq!(lines, expected);
+    }
+
+    #[rstest]
+    #[case::latin("legible", 7, 7, 7)]
+    #[case::japanese("練習", 4, 2, 2)]
+    #[case::combining("a\u{301}b", 2, 2, 2)]
+    #[case::illegible_prefix("\u{200B}\u{200B}a", 1, 3, 1)]
+    #[case::emoji("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}", 2, 1, 1)]
+    fn measure_text_then_width_and_counts_eq(
+        #[case] text: &str,
+        #[case] width: usize,
+        #[case] grapheme_count: usize,
+        #[case] legible_grapheme_count: usize,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(text.width().get(), width);
+        assert_eq!(text.grapheme_count().get(), grapheme_count);
+        assert_eq!(text.legible_grapheme_count().get(), legible_grapheme_count);
+    }
+
+    #[rstest]
+    #[case::latin("legible", 7, 7)]
+    #[case::japanese("練習", 4, 4)]
+    #[case::ambiguous("±①", 2, 4)]
+    fn measure_text_in_cjk_context_then_width_eq(
+        #[case] text: &str,
+        #[case] width: usize,
+        #[case] width_cjk: usize,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(text.width().get(), width);
+        assert_eq!(text.width_cjk().get(), width_cjk);
+    }
+
+    #[rstest]
+    fn line_macro_then_line_eq() {
+        let line: &Line = crate::line!("legible");
+        let text: &Text = line.into();
+        assert_eq!(text, "legible");
+    }
+}
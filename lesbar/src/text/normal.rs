@@ -0,0 +1,674 @@
+//! Legible text in a Unicode normalization form.
+
+#![cfg(feature = "alloc")]
+#![cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+
+#[cfg(feature = "serde")]
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use alloc::borrow::{Borrow, Cow, ToOwned};
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use unicode_normalization::UnicodeNormalization as _;
+
+use crate::policy::{LegibilityPolicy, Standard};
+use crate::{IllegibleError, Legible};
+
+/// A Unicode normalization form per UAX15.
+///
+/// Implementations must be idempotent: normalizing normalized text must not change it.
+pub trait NormalizationForm {
+    fn is_normalized(text: &str) -> bool;
+
+    fn normalize(text: &str) -> String;
+}
+
+/// Normalization Form Canonical Composition.
+#[derive(Clone, Copy, Debug)]
+pub struct Nfc;
+
+impl NormalizationForm for Nfc {
+    fn is_normalized(text: &str) -> bool {
+        unicode_normalization::is_nfc(text)
+    }
+
+    fn normalize(text: &str) -> String {
+        text.nfc().collect()
+    }
+}
+
+/// Normalization Form Canonical Decomposition.
+#[derive(Clone, Copy, Debug)]
+pub struct Nfd;
+
+impl NormalizationForm for Nfd {
+    fn is_normalized(text: &str) -> bool {
+        unicode_normalization::is_nfd(text)
+    }
+
+    fn normalize(text: &str) -> String {
+        text.nfd().collect()
+    }
+}
+
+/// Normalization Form Compatibility Composition.
+#[derive(Clone, Copy, Debug)]
+pub struct Nfkc;
+
+impl NormalizationForm for Nfkc {
+    fn is_normalized(text: &str) -> bool {
+        unicode_normalization::is_nfkc(text)
+    }
+
+    fn normalize(text: &str) -> String {
+        text.nfkc().collect()
+    }
+}
+
+/// Normalization Form Compatibility Decomposition.
+#[derive(Clone, Copy, Debug)]
+pub struct Nfkd;
+
+impl NormalizationForm for Nfkd {
+    fn is_normalized(text: &str) -> bool {
+        unicode_normalization::is_nfkd(text)
+    }
+
+    fn normalize(text: &str) -> String {
+        text.nfkd().collect()
+    }
+}
+
+pub type NfcText = Normalized<str, Nfc>;
+
+pub type NfdText = Normalized<str, Nfd>;
+
+pub type NfkcText = Normalized<str, Nfkc>;
+
+pub type NfkdText = Normalized<str, Nfkd>;
+
+pub type NfcTextBuf = Normalized<String, Nfc>;
+
+pub type NfdTextBuf = Normalized<String, Nfd>;
+
+pub type NfkcTextBuf = Normalized<String, Nfkc>;
+
+pub type NfkdTextBuf = Normalized<String, Nfkd>;
+
+/// Legible text in the normalization form `F`.
+///
+/// Owned `Normalized` text is normalized when it is constructed, including when it is
+/// deserialized. Text that is equivalent per `F` has the same representation, so normalized text
+/// can be compared, hashed, and used as a key regardless of how the input was composed.
+///
+/// Note that compatibility normalization (NFKC and NFKD) can map legible text to illegible text,
+/// such as a Hangul compatibility jamo (`ㄵ`) to a conjoining final jamo with no display width.
+/// Normalization is therefore fallible.
+#[repr(transparent)]
+pub struct Normalized<T, F = Nfc, P = Standard>
+where
+    T: ?Sized,
+{
+    form: PhantomData<fn() -> F>,
+    text: Legible<T, P>,
+}
+
+impl<F, P> Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    const fn from_text_unchecked(text: &Legible<str, P>) -> &Self {
+        // SAFETY: `Normalized` is `repr(transparent)` and its form is zero-sized: `Legible` and
+        //         `Normalized` have the same representation.
+        unsafe { &*(text as *const Legible<str, P> as *const Normalized<str, F, P>) }
+    }
+
+    pub fn to_normalized_text_buf(&self) -> Normalized<String, F, P> {
+        Normalized {
+            form: PhantomData,
+            text: self.text.to_text_buf(),
+        }
+    }
+
+    pub fn as_text(&self) -> &Legible<str, P> {
+        &self.text
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.text.as_str()
+    }
+}
+
+impl<F, P> Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    /// Normalizes legible text into the normalization form `F`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normalized text is illegible per the policy `P`.
+    pub fn from_text_buf(text: Legible<String, P>) -> Result<Self, IllegibleError<String>> {
+        let text = if F::is_normalized(text.as_str()) {
+            text
+        }
+        else {
+            Legible::try_from(F::normalize(text.as_str()))?
+        };
+        Ok(Normalized {
+            form: PhantomData,
+            text,
+        })
+    }
+
+    pub fn into_text_buf(self) -> Legible<String, P> {
+        self.text
+    }
+
+    pub fn into_string(self) -> String {
+        self.text.into_string1().into_string()
+    }
+
+    pub fn as_text_buf(&self) -> &Legible<String, P> {
+        &self.text
+    }
+
+    pub fn as_normalized_text(&self) -> &Normalized<str, F, P> {
+        Normalized::from_text_unchecked(self.text.as_text())
+    }
+}
+
+impl<F, P> AsRef<str> for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<F, P> AsRef<str> for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<F, P> AsRef<Legible<str, P>> for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &Legible<str, P> {
+        self.as_text()
+    }
+}
+
+impl<F, P> AsRef<Legible<str, P>> for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &Legible<str, P> {
+        self.as_text()
+    }
+}
+
+impl<F, P> Borrow<Normalized<str, F, P>> for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn borrow(&self) -> &Normalized<str, F, P> {
+        self.as_normalized_text()
+    }
+}
+
+impl<F, P> Clone for Normalized<String, F, P> {
+    fn clone(&self) -> Self {
+        Normalized {
+            form: PhantomData,
+            text: self.text.clone(),
+        }
+    }
+}
+
+impl<F, P> Debug for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("Normalized")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl<F, P> Debug for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_normalized_text(), formatter)
+    }
+}
+
+impl<F, P> Deref for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    type Target = Legible<str, P>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_text()
+    }
+}
+
+impl<F, P> Deref for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    type Target = Normalized<str, F, P>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_normalized_text()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F, P> Deserialize<'de> for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)
+            .and_then(|text| Normalized::try_from(text).map_err(de::Error::custom))
+    }
+}
+
+impl<F, P> Display for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.as_str())
+    }
+}
+
+impl<F, P> Display for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.as_str())
+    }
+}
+
+impl<F, P> Eq for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+}
+
+impl<F, P> Eq for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+}
+
+impl<F, P> From<Normalized<String, F, P>> for Legible<String, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn from(text: Normalized<String, F, P>) -> Self {
+        text.into_text_buf()
+    }
+}
+
+impl<F, P> From<Normalized<String, F, P>> for String
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn from(text: Normalized<String, F, P>) -> Self {
+        text.into_string()
+    }
+}
+
+impl<F, P> Hash for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_str().hash(state)
+    }
+}
+
+impl<F, P> Hash for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_normalized_text().hash(state)
+    }
+}
+
+impl<F, P> Ord for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<F, P> Ord for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_normalized_text().cmp(other.as_normalized_text())
+    }
+}
+
+impl<F, P> PartialEq for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq(other.as_str())
+    }
+}
+
+impl<F, P> PartialEq for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_normalized_text().eq(other.as_normalized_text())
+    }
+}
+
+impl<F, P> PartialOrd for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F, P> PartialOrd for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F, P> Serialize for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F, P> Serialize for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_normalized_text().serialize(serializer)
+    }
+}
+
+impl<F, P> ToOwned for Normalized<str, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    type Owned = Normalized<String, F, P>;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_normalized_text_buf()
+    }
+}
+
+impl<F, P> TryFrom<&'_ str> for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    type Error = IllegibleError<String>;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Normalized::try_from(F::normalize(text))
+    }
+}
+
+impl<F, P> TryFrom<String> for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    type Error = IllegibleError<String>;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        let text = if F::is_normalized(&text) {
+            text
+        }
+        else {
+            F::normalize(&text)
+        };
+        Ok(Normalized {
+            form: PhantomData,
+            text: Legible::try_from(text)?,
+        })
+    }
+}
+
+impl<F, P> TryFrom<Legible<String, P>> for Normalized<String, F, P>
+where
+    F: NormalizationForm,
+    P: LegibilityPolicy,
+{
+    type Error = IllegibleError<String>;
+
+    fn try_from(text: Legible<String, P>) -> Result<Self, Self::Error> {
+        Normalized::from_text_buf(text)
+    }
+}
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Normalizes the text into the normalization form `F`.
+    ///
+    /// The text is borrowed if it is already normalized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normalized text is illegible per the policy `P`. This is only
+    /// possible with compatibility normalization forms.
+    pub fn normalize<F>(&self) -> Result<Cow<'_, Normalized<str, F, P>>, IllegibleError<String>>
+    where
+        F: NormalizationForm,
+    {
+        if F::is_normalized(self.as_str()) {
+            Ok(Cow::Borrowed(Normalized::from_text_unchecked(self)))
+        }
+        else {
+            Normalized::try_from(F::normalize(self.as_str())).map(Cow::Owned)
+        }
+    }
+
+    /// Normalizes the text into NFC.
+    ///
+    /// See [`Legible::normalize`].
+    pub fn to_nfc(&self) -> Result<Cow<'_, Normalized<str, Nfc, P>>, IllegibleError<String>> {
+        self.normalize()
+    }
+
+    /// Normalizes the text into NFD.
+    ///
+    /// See [`Legible::normalize`].
+    pub fn to_nfd(&self) -> Result<Cow<'_, Normalized<str, Nfd, P>>, IllegibleError<String>> {
+        self.normalize()
+    }
+
+    /// Normalizes the text into NFKC.
+    ///
+    /// See [`Legible::normalize`].
+    pub fn to_nfkc(&self) -> Result<Cow<'_, Normalized<str, Nfkc, P>>, IllegibleError<String>> {
+        self.normalize()
+    }
+
+    /// Normalizes the text into NFKD.
+    ///
+    /// See [`Legible::normalize`].
+    pub fn to_nfkd(&self) -> Result<Cow<'_, Normalized<str, Nfkd, P>>, IllegibleError<String>> {
+        self.normalize()
+    }
+}
+
+impl<P> Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Normalizes the text into the normalization form `F`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normalized text is illegible per the policy `P`. This is only
+    /// possible with compatibility normalization forms.
+    pub fn into_normalized<F>(self) -> Result<Normalized<String, F, P>, IllegibleError<String>>
+    where
+        F: NormalizationForm,
+    {
+        Normalized::from_text_buf(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::borrow::Cow;
+    use rstest::rstest;
+    #[cfg(feature = "serde")]
+    use serde_test::Token;
+    use std::collections::HashSet;
+
+    use crate::text::{Nfc, NfcTextBuf, Nfd, NfkcTextBuf, Text, TextBuf};
+
+    const COMPOSED: &str = "caf\u{E9}";
+    const DECOMPOSED: &str = "cafe\u{301}";
+
+    #[rstest]
+    fn normalize_composed_text_then_nfc_borrowed_and_nfd_owned() {
+        let text = Text::try_from_str(COMPOSED).unwrap();
+        assert!(matches!(text.to_nfc().unwrap(), Cow::Borrowed(_)));
+        let nfd = text.to_nfd().unwrap();
+        assert!(matches!(nfd, Cow::Owned(_)));
+        assert_eq!(nfd.as_str(), DECOMPOSED);
+    }
+
+    #[rstest]
+    #[case::fullwidth("\u{FF21}\u{FF22}", "AB")]
+    #[case::ligature("\u{FB01}le", "file")]
+    fn normalize_text_to_nfkc_then_eq(#[case] text: &str, #[case] expected: &str) {
+        let text = Text::try_from_str(text).unwrap();
+        assert_eq!(text.to_nfkc().unwrap().as_str(), expected);
+    }
+
+    #[rstest]
+    fn normalize_compatibility_jamo_to_nfkc_then_illegible_error() {
+        let text = Text::try_from_str("\u{3135}").unwrap();
+        assert!(text.to_nfd().is_ok());
+        assert!(text.to_nfkc().is_err());
+    }
+
+    #[rstest]
+    fn normalize_equivalent_text_bufs_then_eq_and_hash_eq() {
+        let composed = NfcTextBuf::try_from(COMPOSED).unwrap();
+        let decomposed = NfcTextBuf::try_from(TextBuf::try_from(DECOMPOSED).unwrap()).unwrap();
+        assert_eq!(composed, decomposed);
+        assert_eq!(decomposed.as_str(), COMPOSED);
+
+        let keys: HashSet<NfcTextBuf> = [composed, decomposed].into_iter().collect();
+        assert_eq!(keys.len(), 1);
+    }
+
+    #[rstest]
+    fn into_normalized_text_buf_then_eq() {
+        let text = TextBuf::try_from(COMPOSED).unwrap();
+        let text = text.into_normalized::<Nfd>().unwrap();
+        assert_eq!(text.as_str(), DECOMPOSED);
+        let text = text.into_text_buf().into_normalized::<Nfc>().unwrap();
+        assert_eq!(text.as_str(), COMPOSED);
+    }
+
+    #[rstest]
+    fn try_from_illegible_str_then_nfkc_illegible_error() {
+        assert!(NfkcTextBuf::try_from("\u{3135}").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn deserialize_decomposed_text_then_nfc_text_buf_eq() {
+        let text = NfcTextBuf::try_from(COMPOSED).unwrap();
+        serde_test::assert_de_tokens(&text, &[Token::BorrowedStr(DECOMPOSED)]);
+        serde_test::assert_ser_tokens(&text, &[Token::Str(COMPOSED)]);
+    }
+}
@@ -0,0 +1,630 @@
+//! Legible text enforced per a PRECIS profile.
+
+#![cfg(feature = "precis")]
+#![cfg_attr(docsrs, doc(cfg(feature = "precis")))]
+
+#[cfg(feature = "serde")]
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use alloc::borrow::{Borrow, Cow, ToOwned};
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::policy::{LegibilityPolicy, Standard};
+use crate::precis::{
+    Nickname, OpaqueString, PrecisError, Profile, UsernameCaseMapped, UsernameCasePreserved,
+};
+use crate::{IllegibleError, Legible, StrExt as _};
+
+pub type UsernameCaseMappedText = Enforced<str, UsernameCaseMapped>;
+
+pub type UsernameCasePreservedText = Enforced<str, UsernameCasePreserved>;
+
+pub type OpaqueStringText = Enforced<str, OpaqueString>;
+
+pub type NicknameText = Enforced<str, Nickname>;
+
+pub type UsernameCaseMappedTextBuf = Enforced<String, UsernameCaseMapped>;
+
+pub type UsernameCasePreservedTextBuf = Enforced<String, UsernameCasePreserved>;
+
+pub type OpaqueStringTextBuf = Enforced<String, OpaqueString>;
+
+pub type NicknameTextBuf = Enforced<String, Nickname>;
+
+/// An error that occurs when a PRECIS profile is enforced on text.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum EnforcementError {
+    /// The text violates the profile.
+    Precis(PrecisError),
+    /// The enforced text is illegible.
+    Illegible(IllegibleError<String>),
+}
+
+impl Display for EnforcementError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EnforcementError::Precis(error) => write!(formatter, "{error}"),
+            EnforcementError::Illegible(error) => write!(formatter, "{error}"),
+        }
+    }
+}
+
+impl Error for EnforcementError {}
+
+impl From<IllegibleError<String>> for EnforcementError {
+    fn from(error: IllegibleError<String>) -> Self {
+        EnforcementError::Illegible(error)
+    }
+}
+
+impl From<PrecisError> for EnforcementError {
+    fn from(error: PrecisError) -> Self {
+        EnforcementError::Precis(error)
+    }
+}
+
+/// Legible text that has been enforced per the PRECIS profile `R`.
+///
+/// Owned `Enforced` text is enforced when it is constructed, including when it is deserialized:
+/// the rules of `R` are applied and the output must be valid per `R` and legible per the policy
+/// `P`. Text is compared and hashed per the comparison rules of `R`, so, for example,
+/// [`NicknameTextBuf`]s are equal regardless of case.
+///
+/// Note that some text is valid per a profile but not legible, such as a lone combining mark in a
+/// username. Enforcement is therefore fallible for both reasons. See [`EnforcementError`].
+#[repr(transparent)]
+pub struct Enforced<T, R, P = Standard>
+where
+    T: ?Sized,
+{
+    profile: PhantomData<fn() -> R>,
+    text: Legible<T, P>,
+}
+
+impl<R, P> Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    const fn from_text_unchecked(text: &Legible<str, P>) -> &Self {
+        // SAFETY: `Enforced` is `repr(transparent)` and its profile is zero-sized: `Legible` and
+        //         `Enforced` have the same representation.
+        unsafe { &*(text as *const Legible<str, P> as *const Enforced<str, R, P>) }
+    }
+
+    pub fn to_enforced_text_buf(&self) -> Enforced<String, R, P> {
+        Enforced {
+            profile: PhantomData,
+            text: self.text.to_text_buf(),
+        }
+    }
+
+    pub fn as_text(&self) -> &Legible<str, P> {
+        &self.text
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.text.as_str()
+    }
+
+    fn comparable(&self) -> Cow<'_, str> {
+        R::to_comparable(self.as_str())
+    }
+}
+
+impl<R, P> Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    /// Enforces the profile `R` on legible text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text violates the profile `R` or if the enforced text is illegible
+    /// per the policy `P`.
+    pub fn from_text_buf(text: Legible<String, P>) -> Result<Self, EnforcementError> {
+        Enforced::try_from(text.as_str())
+    }
+
+    pub fn into_text_buf(self) -> Legible<String, P> {
+        self.text
+    }
+
+    pub fn into_string(self) -> String {
+        self.text.into_string1().into_string()
+    }
+
+    pub fn as_text_buf(&self) -> &Legible<String, P> {
+        &self.text
+    }
+
+    pub fn as_enforced_text(&self) -> &Enforced<str, R, P> {
+        Enforced::from_text_unchecked(self.text.as_text())
+    }
+}
+
+impl<R, P> AsRef<str> for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<R, P> AsRef<str> for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<R, P> AsRef<Legible<str, P>> for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &Legible<str, P> {
+        self.as_text()
+    }
+}
+
+impl<R, P> AsRef<Legible<str, P>> for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn as_ref(&self) -> &Legible<str, P> {
+        self.as_text()
+    }
+}
+
+impl<R, P> Borrow<Enforced<str, R, P>> for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn borrow(&self) -> &Enforced<str, R, P> {
+        self.as_enforced_text()
+    }
+}
+
+impl<R, P> Clone for Enforced<String, R, P> {
+    fn clone(&self) -> Self {
+        Enforced {
+            profile: PhantomData,
+            text: self.text.clone(),
+        }
+    }
+}
+
+impl<R, P> Debug for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("Enforced")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl<R, P> Debug for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_enforced_text(), formatter)
+    }
+}
+
+impl<R, P> Deref for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    type Target = Legible<str, P>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_text()
+    }
+}
+
+impl<R, P> Deref for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    type Target = Enforced<str, R, P>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_enforced_text()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, R, P> Deserialize<'de> for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)
+            .and_then(|text| Enforced::try_from(text).map_err(de::Error::custom))
+    }
+}
+
+impl<R, P> Display for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.as_str())
+    }
+}
+
+impl<R, P> Display for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.as_str())
+    }
+}
+
+impl<R, P> Eq for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+}
+
+impl<R, P> Eq for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+}
+
+impl<R, P> From<Enforced<String, R, P>> for Legible<String, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn from(text: Enforced<String, R, P>) -> Self {
+        text.into_text_buf()
+    }
+}
+
+impl<R, P> From<Enforced<String, R, P>> for String
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn from(text: Enforced<String, R, P>) -> Self {
+        text.into_string()
+    }
+}
+
+impl<R, P> Hash for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.comparable().hash(state)
+    }
+}
+
+impl<R, P> Hash for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_enforced_text().hash(state)
+    }
+}
+
+impl<R, P> Ord for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.comparable().cmp(&other.comparable())
+    }
+}
+
+impl<R, P> Ord for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_enforced_text().cmp(other.as_enforced_text())
+    }
+}
+
+impl<R, P> PartialEq for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.comparable() == other.comparable()
+    }
+}
+
+impl<R, P> PartialEq for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_enforced_text().eq(other.as_enforced_text())
+    }
+}
+
+impl<R, P> PartialOrd for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R, P> PartialOrd for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R, P> Serialize for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R, P> Serialize for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_enforced_text().serialize(serializer)
+    }
+}
+
+impl<R, P> ToOwned for Enforced<str, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    type Owned = Enforced<String, R, P>;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_enforced_text_buf()
+    }
+}
+
+impl<R, P> TryFrom<&'_ str> for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    type Error = EnforcementError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        let text = text.enforce_precis::<R>()?;
+        Ok(Enforced {
+            profile: PhantomData,
+            text: Legible::try_from(text)?,
+        })
+    }
+}
+
+impl<R, P> TryFrom<String> for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    type Error = EnforcementError;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        Enforced::try_from(text.as_str())
+    }
+}
+
+impl<R, P> TryFrom<Legible<String, P>> for Enforced<String, R, P>
+where
+    R: Profile,
+    P: LegibilityPolicy,
+{
+    type Error = EnforcementError;
+
+    fn try_from(text: Legible<String, P>) -> Result<Self, Self::Error> {
+        Enforced::from_text_buf(text)
+    }
+}
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Enforces the PRECIS profile `R` on the text.
+    ///
+    /// The text is borrowed if enforcement does not change it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text violates the profile `R` or if the enforced text is illegible
+    /// per the policy `P`.
+    pub fn enforce<R>(&self) -> Result<Cow<'_, Enforced<str, R, P>>, EnforcementError>
+    where
+        R: Profile,
+    {
+        let text = self.as_str().enforce_precis::<R>()?;
+        if text == self.as_str() {
+            Ok(Cow::Borrowed(Enforced::from_text_unchecked(self)))
+        }
+        else {
+            Ok(Cow::Owned(Enforced {
+                profile: PhantomData,
+                text: Legible::try_from(text)?,
+            }))
+        }
+    }
+}
+
+impl<P> Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Enforces the PRECIS profile `R` on the text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text violates the profile `R` or if the enforced text is illegible
+    /// per the policy `P`.
+    pub fn into_enforced<R>(self) -> Result<Enforced<String, R, P>, EnforcementError>
+    where
+        R: Profile,
+    {
+        Enforced::from_text_buf(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::borrow::Cow;
+    use rstest::rstest;
+    #[cfg(feature = "serde")]
+    use serde_test::Token;
+    use std::collections::HashSet;
+
+    use crate::precis::{Nickname, UsernameCaseMapped};
+    use crate::text::{
+        EnforcementError, NicknameTextBuf, OpaqueStringTextBuf, Text, UsernameCaseMappedTextBuf,
+        UsernameCasePreservedTextBuf,
+    };
+
+    #[rstest]
+    fn enforce_enforced_text_then_borrowed() {
+        let text = Text::try_from_str("juliet").unwrap();
+        assert!(matches!(
+            text.enforce::<UsernameCaseMapped>().unwrap(),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[rstest]
+    fn enforce_text_then_owned_and_eq() {
+        let text = Text::try_from_str("  Juliet   Capulet ").unwrap();
+        let nickname = text.enforce::<Nickname>().unwrap();
+        assert!(matches!(nickname, Cow::Owned(_)));
+        assert_eq!(nickname.as_str(), "Juliet Capulet");
+    }
+
+    #[rstest]
+    #[case::lowercase("juliet", "juliet")]
+    #[case::uppercase("Juliet", "juliet")]
+    #[case::fullwidth("\u{FF2A}uliet", "juliet")]
+    fn try_from_str_then_username_case_mapped_eq(#[case] text: &str, #[case] expected: &str) {
+        let username = UsernameCaseMappedTextBuf::try_from(text).unwrap();
+        assert_eq!(username.as_str(), expected);
+    }
+
+    #[rstest]
+    fn try_from_str_then_username_case_preserved_eq() {
+        let username = UsernameCasePreservedTextBuf::try_from("\u{FF2A}uliet").unwrap();
+        assert_eq!(username.as_str(), "Juliet");
+    }
+
+    #[rstest]
+    #[case::space("juliet capulet")]
+    #[case::roman_numeral("\u{2163}")]
+    fn try_from_str_then_username_precis_error(#[case] text: &str) {
+        assert!(matches!(
+            UsernameCaseMappedTextBuf::try_from(text),
+            Err(EnforcementError::Precis(_)),
+        ));
+    }
+
+    #[rstest]
+    fn try_from_combining_mark_then_username_illegible_error() {
+        assert!(matches!(
+            UsernameCaseMappedTextBuf::try_from("\u{301}"),
+            Err(EnforcementError::Illegible(_)),
+        ));
+    }
+
+    #[rstest]
+    fn try_from_str_then_opaque_string_eq() {
+        let secret = OpaqueStringTextBuf::try_from("Correct\u{A0}Horse").unwrap();
+        assert_eq!(secret.as_str(), "Correct Horse");
+    }
+
+    #[rstest]
+    fn try_from_nicknames_with_different_case_then_eq_and_hash_eq() {
+        let nickname = NicknameTextBuf::try_from("Juliet Capulet").unwrap();
+        let other = NicknameTextBuf::try_from(" juliet  CAPULET").unwrap();
+        assert_eq!(other.as_str(), "juliet CAPULET");
+        assert_eq!(nickname, other);
+
+        let keys: HashSet<NicknameTextBuf> = [nickname, other].into_iter().collect();
+        assert_eq!(keys.len(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn deserialize_nickname_then_nickname_text_buf_eq() {
+        let nickname = NicknameTextBuf::try_from("Juliet Capulet").unwrap();
+        serde_test::assert_de_tokens(&nickname, &[Token::BorrowedStr("  Juliet  Capulet ")]);
+        serde_test::assert_ser_tokens(&nickname, &[Token::Str("Juliet Capulet")]);
+    }
+}
@@ -0,0 +1,224 @@
+//! Width-aware truncation of legible text.
+
+#![cfg(feature = "alloc")]
+#![cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+
+use alloc::borrow::{Cow, ToOwned};
+use alloc::string::String;
+
+use crate::policy::LegibilityPolicy;
+use crate::text::Columns;
+use crate::{Legible, StrExt as _};
+
+/// The placement of an ellipsis in truncated text.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum EllipsisPlacement {
+    /// The ellipsis replaces the start of the text.
+    Start,
+    /// The ellipsis replaces the middle of the text.
+    Middle,
+    /// The ellipsis replaces the end of the text.
+    #[default]
+    End,
+}
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Truncates the text to the given display width in columns, inserting an ellipsis where text
+    /// is removed.
+    ///
+    /// The text is borrowed if it is no wider than `width`. Otherwise, the text is cut on
+    /// grapheme cluster boundaries and illegible grapheme clusters at the cut are removed. Display
+    /// width is measured in the context of `width` (see [`Columns`]).
+    ///
+    /// The output is no wider than `width` or the ellipsis, whichever is wider: if the ellipsis is
+    /// wider than `width`, then the output is only the ellipsis and so is wider than `width`. The
+    /// output is also only the ellipsis if joining it with the remaining text is not legible per
+    /// the policy `P`.
+    pub fn truncate_to_width<'t>(
+        &'t self,
+        width: impl Into<Columns>,
+        ellipsis: &Legible<str, P>,
+        placement: EllipsisPlacement,
+    ) -> Cow<'t, Legible<str, P>> {
+        match self.truncated_to_width(width.into(), ellipsis, placement) {
+            Some(text) => Cow::Owned(text),
+            _ => Cow::Borrowed(self),
+        }
+    }
+
+    fn truncated_to_width(
+        &self,
+        width: Columns,
+        ellipsis: &Legible<str, P>,
+        placement: EllipsisPlacement,
+    ) -> Option<Legible<String, P>> {
+        if width.measure(self.as_str()) <= width.width() {
+            return None;
+        }
+        let budget = width
+            .width()
+            .saturating_sub(width.measure(ellipsis.as_str()));
+        let (head, tail) = match placement {
+            EllipsisPlacement::Start => (0, budget),
+            EllipsisPlacement::Middle => (budget - (budget / 2), budget / 2),
+            EllipsisPlacement::End => (budget, 0),
+        };
+        let end = self.legible_prefix_end(Columns::new(head, width.context()));
+        let start = self
+            .legible_suffix_start(Columns::new(tail, width.context()))
+            .max(end);
+
+        let mut text =
+            String::with_capacity(end + ellipsis.len().get() + (self.len().get() - start));
+        text.push_str(&self[..end]);
+        text.push_str(ellipsis.as_str());
+        text.push_str(&self[start..]);
+        // Joining the ellipsis with the remaining text may form different grapheme clusters at
+        // the cuts, so the output is checked.
+        Some(Legible::try_from(text).unwrap_or_else(|_| ellipsis.to_owned()))
+    }
+
+    // Gets the end of the longest prefix that is no wider than `width` and ends with a legible
+    // grapheme cluster, or zero if there is no such prefix.
+    fn legible_prefix_end(&self, width: Columns) -> usize {
+        let mut end = 0;
+        let mut prefix = 0;
+        for (index, grapheme) in self.grapheme_indices() {
+            prefix += width.measure_grapheme(grapheme);
+            if prefix > width.width() {
+                break;
+            }
+            if P::is_legible_grapheme(grapheme) {
+                end = index + grapheme.as_str().len();
+            }
+        }
+        end
+    }
+
+    // Gets the start of the longest suffix that is no wider than `width` and starts with a legible
+    // grapheme cluster, or the length of the text if there is no such suffix.
+    fn legible_suffix_start(&self, width: Columns) -> usize {
+        let mut start = self.len().get();
+        let mut suffix = 0;
+        for (index, grapheme) in self.grapheme_indices().rev() {
+            suffix += width.measure_grapheme(grapheme);
+            if suffix > width.width() {
+                break;
+            }
+            if P::is_legible_grapheme(grapheme) {
+                start = index;
+            }
+        }
+        start
+    }
+}
+
+impl<P> Legible<String, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Truncates the text in place to the given display width in columns, inserting an ellipsis
+    /// where text is removed.
+    ///
+    /// See [`Legible::truncate_to_width`].
+    pub fn truncate_to_width_in_place(
+        &mut self,
+        width: impl Into<Columns>,
+        ellipsis: &Legible<str, P>,
+        placement: EllipsisPlacement,
+    ) {
+        if let Some(text) = self
+            .as_text()
+            .truncated_to_width(width.into(), ellipsis, placement)
+        {
+            *self = text;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::borrow::Cow;
+    use rstest::rstest;
+
+    use crate::text::{Columns, EllipsisPlacement, Text, TextBuf};
+
+    #[rstest]
+    #[case::end("legible text", 8, EllipsisPlacement::End, "legible…")]
+    #[case::start("legible text", 8, EllipsisPlacement::Start, "…le text")]
+    #[case::middle("legible text", 8, EllipsisPlacement::Middle, "legi…ext")]
+    #[case::wide("練習しなくては", 7, EllipsisPlacement::End, "練習し…")]
+    #[case::wide_middle("練習しなくては", 7, EllipsisPlacement::Middle, "練…は")]
+    #[case::emoji(
+        "a\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}b",
+        3,
+        EllipsisPlacement::End,
+        "a…"
+    )]
+    #[case::illegible_at_cut("abc\u{200B}\u{200B}def", 4, EllipsisPlacement::End, "abc…")]
+    #[case::too_narrow("legible", 0, EllipsisPlacement::End, "…")]
+    fn truncate_text_to_width_then_truncated_eq(
+        #[case] text: &str,
+        #[case] width: usize,
+        #[case] placement: EllipsisPlacement,
+        #[case] expected: &str,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        let ellipsis = Text::try_from_str("…").unwrap();
+        let truncated = text.truncate_to_width(width, ellipsis, placement);
+        assert!(matches!(truncated, Cow::Owned(_)));
+        assert_eq!(truncated.as_ref(), expected);
+    }
+
+    #[rstest]
+    #[case::end("±①±①", 6, EllipsisPlacement::End, "±①…")]
+    #[case::middle("±①±①", 6, EllipsisPlacement::Middle, "±…①")]
+    fn truncate_text_to_cjk_width_then_truncated_eq(
+        #[case] text: &str,
+        #[case] width: usize,
+        #[case] placement: EllipsisPlacement,
+        #[case] expected: &str,
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        let ellipsis = Text::try_from_str("…").unwrap();
+        let truncated = text.truncate_to_width(Columns::cjk(width), ellipsis, placement);
+        assert_eq!(truncated.as_ref(), expected);
+    }
+
+    #[rstest]
+    #[case::narrower("legible", 8)]
+    #[case::exact("legible", 7)]
+    fn truncate_text_to_wider_width_then_borrowed(#[case] text: &str, #[case] width: usize) {
+        let text = Text::try_from_str(text).unwrap();
+        let ellipsis = Text::try_from_str("…").unwrap();
+        assert!(matches!(
+            text.truncate_to_width(width, ellipsis, EllipsisPlacement::End),
+            Cow::Borrowed(_),
+        ));
+    }
+
+    #[rstest]
+    #[case::zero(0)]
+    #[case::narrower_than_ellipsis(2)]
+    #[case::ellipsis(3)]
+    fn truncate_text_to_width_with_wide_ellipsis_then_only_ellipsis(#[case] width: usize) {
+        let text = Text::try_from_str("legible").unwrap();
+        let ellipsis = Text::try_from_str("...").unwrap();
+        let truncated = text.truncate_to_width(width, ellipsis, EllipsisPlacement::End);
+        assert_eq!(truncated.as_ref(), ellipsis);
+        assert!(Columns::from(width).measure(truncated.as_str()) <= width.max(3));
+    }
+
+    #[rstest]
+    fn truncate_text_buf_to_width_in_place_then_text_buf_eq() {
+        let mut text = TextBuf::try_from("legible text").unwrap();
+        let ellipsis = Text::try_from_str("...").unwrap();
+        text.truncate_to_width_in_place(10, ellipsis, EllipsisPlacement::End);
+        assert_eq!(text, "legible...");
+    }
+}
@@ -0,0 +1,191 @@
+//! Width-aware wrapping of legible text.
+
+#![cfg(feature = "alloc")]
+#![cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+
+use alloc::borrow::Cow;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::{FusedIterator, Peekable};
+use mitsein::iter1::Iterator1;
+use unicode_linebreak::BreakOpportunity;
+
+use crate::policy::{LegibilityPolicy, Standard};
+use crate::text::Columns;
+use crate::{Legible, StrExt as _};
+
+/// An iterator over the wrapped lines of legible text.
+///
+/// See [`Legible::wrap`].
+pub struct Wrap<'t, P = Standard> {
+    text: &'t Legible<str, P>,
+    input: &'t str,
+    width: Columns,
+    is_empty: bool,
+}
+
+impl<'t, P> Wrap<'t, P>
+where
+    P: LegibilityPolicy,
+{
+    // Splits the next line from the input. The line is empty if the input is empty.
+    fn split_line(&mut self) -> &'t str {
+        let input = self.input;
+        let mut end = None;
+        let mut start = 0;
+        let mut width = 0;
+        for (index, opportunity) in unicode_linebreak::linebreaks(input) {
+            let segment = &input[start..index];
+            // Whitespace at the end of a line does not count toward its width.
+            if width + self.width.measure(segment.trim_end()) > self.width.width() {
+                break;
+            }
+            width += self.width.measure(segment);
+            end = Some(index);
+            start = index;
+            if let BreakOpportunity::Mandatory = opportunity {
+                break;
+            }
+        }
+        let end = end.unwrap_or_else(|| {
+            // There is no line break opportunity within the width, so the line is split between
+            // grapheme clusters. The line has at least one grapheme cluster, even if it is too
+            // wide.
+            let mut width = 0;
+            input
+                .grapheme_indices()
+                .find_map(|(index, grapheme)| {
+                    width += self.width.measure_grapheme(grapheme);
+                    (index > 0 && width > self.width.width()).then_some(index)
+                })
+                .unwrap_or(input.len())
+        });
+        let (line, input) = input.split_at(end);
+        self.input = input;
+        line.trim_end()
+    }
+}
+
+impl<P> Clone for Wrap<'_, P> {
+    fn clone(&self) -> Self {
+        Wrap {
+            text: self.text,
+            input: self.input,
+            width: self.width,
+            is_empty: self.is_empty,
+        }
+    }
+}
+
+impl<P> Debug for Wrap<'_, P> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Wrap")
+            .field("input", &self.input)
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'t, P> FusedIterator for Wrap<'t, P> where P: 't + LegibilityPolicy {}
+
+impl<'t, P> Iterator for Wrap<'t, P>
+where
+    P: 't + LegibilityPolicy,
+{
+    type Item = Cow<'t, Legible<str, P>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.input.is_empty() {
+            if let Ok(line) = Legible::try_from_str(self.split_line()) {
+                self.is_empty = false;
+                return Some(Cow::Borrowed(line));
+            }
+        }
+        if self.is_empty {
+            self.is_empty = false;
+            Some(Cow::Borrowed(self.text))
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl<P> Legible<str, P>
+where
+    P: LegibilityPolicy,
+{
+    /// Gets an iterator over the lines of the text wrapped to the given display width in columns.
+    ///
+    /// Lines are broken at line break opportunities per UAX14. Words that are wider than `width`
+    /// are broken between grapheme clusters. Whitespace at the end of lines is removed and lines
+    /// that are not legible per the policy `P` are skipped. Lines are no wider than `width` unless
+    /// a single grapheme cluster is wider than `width`. Display width is measured in the context of
+    /// `width` (see [`Columns`]).
+    ///
+    /// If no line is legible (for example, when the text has only whitespace), then the text is
+    /// yielded as is.
+    pub fn wrap(&self, width: impl Into<Columns>) -> Iterator1<Peekable<Wrap<'_, P>>> {
+        Iterator1::try_from_iter(Wrap {
+            text: self,
+            input: self.as_str(),
+            width: width.into(),
+            is_empty: true,
+        })
+        .expect("wrapped text has no lines")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use rstest::rstest;
+    use std::vec::Vec;
+
+    use crate::text::{Columns, Text};
+
+    #[rstest]
+    #[case::narrow("legible", 16, &["legible"])]
+    #[case::words("the quick brown fox", 10, &["the quick", "brown fox"])]
+    #[case::trailing_whitespace("the   quick   brown", 9, &["the", "quick", "brown"])]
+    #[case::line_breaks("the\nquick brown\n\nfox", 16, &["the", "quick brown", "fox"])]
+    #[case::long_word("legibility", 4, &["legi", "bili", "ty"])]
+    #[case::long_word_after_word("a legibility", 4, &["a", "legi", "bili", "ty"])]
+    #[case::wide("練習しなくては", 5, &["練習", "しな", "くて", "は"])]
+    #[case::emoji(
+        "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+        2,
+        &["\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}", "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}"],
+    )]
+    #[case::too_narrow("練習", 1, &["練", "習"])]
+    #[case::only_whitespace("  \n ", 4, &["  \n "])]
+    fn wrap_text_then_lines_eq(
+        #[case] text: &str,
+        #[case] width: usize,
+        #[case] expected: &[&str],
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        let lines: Vec<_> = text.wrap(width).into_iter().collect();
+        assert_eq!(
+            lines.iter().map(|line| line.as_str()).collect::<Vec<_>>(),
+            expected,
+        );
+    }
+
+    #[rstest]
+    #[case::narrow("± ① ±", 6, &["± ①", "±"])]
+    #[case::long_word("±①±①", 4, &["±①", "±①"])]
+    fn wrap_text_to_cjk_width_then_lines_eq(
+        #[case] text: &str,
+        #[case] width: usize,
+        #[case] expected: &[&str],
+    ) {
+        let text = Text::try_from_str(text).unwrap();
+        let lines: Vec<_> = text.wrap(Columns::cjk(width)).into_iter().collect();
+        assert_eq!(
+            lines.iter().map(|line| line.as_str()).collect::<Vec<_>>(),
+            expected,
+        );
+    }
+}